@@ -0,0 +1,198 @@
+//! This crate provides `#[derive(RangeCheck)]`, which writes the range
+//! checks for the fields of a struct, so you don’t have to write a
+//! constructor with one `check_range` call per field.
+//!
+//! Mark each field that should be checked with a `#[range(...)]` attribute
+//! holding the range, using any range that `check_range` accepts. The derive
+//! then generates two methods:
+//!
+//! - `validate(&self)`, which checks every marked field in order, and
+//! - `try_new(...)`, a checked constructor that takes every field in order,
+//!   builds the struct, and validates it.
+//!
+//! Both return a [`FieldError`](../range_check/struct.FieldError.html) for
+//! the first field that is out of range, which holds the name of the field
+//! alongside its `OutOfRangeError`:
+//!
+//! ```
+//! # extern crate range_check;
+//! use range_check_derive::RangeCheck;
+//!
+//! #[derive(RangeCheck)]
+//! struct Clock {
+//!     #[range(0..24)]
+//!     hour: i8,
+//!
+//!     #[range(0..60)]
+//!     minute: i8,
+//! }
+//!
+//! assert!(Clock::try_new(23, 59).is_ok());
+//!
+//! let err = Clock::try_new(23, 60).err().unwrap();
+//! assert_eq!(err.field(), "minute");
+//! assert_eq!(err.to_string(), "minute: value (60) above range (0..60)");
+//! ```
+//!
+//! The error holds values of the same type as the first marked field. When
+//! the fields have different types, use a `#[range_check(error = ...)]`
+//! attribute on the struct to pick a type that every field’s type can be
+//! converted into using `From`, just like `OutOfRangeError::generify`:
+//!
+//! ```
+//! # extern crate range_check;
+//! use range_check::FieldError;
+//! use range_check_derive::RangeCheck;
+//!
+//! #[derive(RangeCheck)]
+//! #[range_check(error = i16)]
+//! struct Clock {
+//!     #[range(0..60)]
+//!     second: i8,
+//!
+//!     #[range(0..1000)]
+//!     millisecond: i16,
+//! }
+//!
+//! let err: FieldError<i16> = Clock::try_new(45, 1000).err().unwrap();
+//! assert_eq!(err.field(), "millisecond");
+//! ```
+//!
+//! The generated code uses types from `range_check`, so use the version of
+//! this crate that matches it: `range_check_derive` 0.3 goes with
+//! `range_check` 0.3.
+
+#![warn(missing_copy_implementations)]
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+#![warn(trivial_casts, trivial_numeric_casts)]
+#![warn(unused_qualifications)]
+#![warn(unused_results)]
+
+extern crate proc_macro;
+extern crate proc_macro2;
+extern crate quote;
+extern crate syn;
+
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Data, DeriveInput, Error, Expr, Fields, Type};
+
+
+/// Derives `validate` and `try_new` methods that check the fields marked
+/// with `#[range(...)]` attributes. See the crate documentation for details.
+#[proc_macro_derive(RangeCheck, attributes(range, range_check))]
+pub fn derive_range_check(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match expand(&input) {
+        Ok(tokens)  => tokens.into(),
+        Err(error)  => error.to_compile_error().into(),
+    }
+}
+
+
+/// A field of the struct, and the ranges it should be checked against.
+struct CheckedField<'a> {
+    name: String,
+    member: TokenStream,
+    argument: syn::Ident,
+    ty: &'a Type,
+    ranges: Vec<Expr>,
+}
+
+fn expand(input: &DeriveInput) -> Result<TokenStream, Error> {
+    let struct_name = &input.ident;
+
+    let struct_fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => return Err(Error::new_spanned(input, "RangeCheck can only be derived for structs")),
+    };
+
+    let fields = struct_fields.iter().enumerate().map(|(index, field)| {
+        let (name, member, argument) = match &field.ident {
+            Some(ident) => (ident.to_string(), quote!(#ident), ident.clone()),
+            None => {
+                let index = syn::Index::from(index);
+                (index.index.to_string(), quote!(#index), format_ident!("field_{}", index.index))
+            }
+        };
+
+        let ranges = field.attrs.iter()
+                                .filter(|attr| attr.path().is_ident("range"))
+                                .map(|attr| attr.parse_args::<Expr>())
+                                .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(CheckedField { name, member, argument, ty: &field.ty, ranges })
+    }).collect::<Result<Vec<_>, Error>>()?;
+
+    let error_type = match error_type(input)? {
+        Some(ty) => ty,
+        None => match fields.iter().find(|f| ! f.ranges.is_empty()) {
+            Some(field) => field.ty.clone(),
+            None => return Err(Error::new(Span::call_site(), "RangeCheck needs at least one field with a #[range(...)] attribute, or a #[range_check(error = ...)] attribute")),
+        },
+    };
+
+    let checks = fields.iter().flat_map(|field| {
+        let name = &field.name;
+        let member = &field.member;
+
+        field.ranges.iter().map(move |range| quote! {
+            let _ = ::range_check::Check::check_range(self.#member, #range)
+                .map_err(|e| ::range_check::FieldError::new(#name, ::range_check::OutOfRangeError::generify(e)))?;
+        })
+    });
+
+    let arguments = fields.iter().map(|f| &f.argument);
+    let types = fields.iter().map(|f| f.ty);
+    let names = fields.iter().map(|f| &f.argument);
+    let construction = match struct_fields {
+        Fields::Named(_)    => quote!(#struct_name { #( #names ),* }),
+        Fields::Unnamed(_)  => quote!(#struct_name ( #( #names ),* )),
+        Fields::Unit        => quote!(#struct_name),
+    };
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let constructor_doc = format!("Creates a new `{}` from its fields, checking that each one is within its range.", struct_name);
+
+    Ok(quote! {
+        impl #impl_generics #struct_name #ty_generics #where_clause {
+
+            /// Checks that every field with a range is within it, returning
+            /// an error for the first field that is not.
+            pub fn validate(&self) -> ::std::result::Result<(), ::range_check::FieldError<#error_type>> {
+                #( #checks )*
+                Ok(())
+            }
+
+            #[doc = #constructor_doc]
+            #[allow(clippy::too_many_arguments)]
+            pub fn try_new( #( #arguments: #types ),* ) -> ::std::result::Result<Self, ::range_check::FieldError<#error_type>> {
+                let value = #construction;
+                value.validate()?;
+                Ok(value)
+            }
+        }
+    })
+}
+
+/// Reads the type given in a `#[range_check(error = ...)]` attribute on the
+/// struct, if there is one.
+fn error_type(input: &DeriveInput) -> Result<Option<Type>, Error> {
+    let mut error_type = None;
+
+    for attr in input.attrs.iter().filter(|attr| attr.path().is_ident("range_check")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("error") {
+                error_type = Some(meta.value()?.parse()?);
+                Ok(())
+            }
+            else {
+                Err(meta.error("unknown range_check attribute; expected `error`"))
+            }
+        })?;
+    }
+
+    Ok(error_type)
+}
@@ -0,0 +1,106 @@
+extern crate range_check;
+extern crate range_check_derive;
+
+use range_check::{Bounds, ErrorKind, FieldError, Violation};
+use range_check_derive::RangeCheck;
+
+use std::ops::Bound;
+
+
+#[derive(RangeCheck, PartialEq, Debug)]
+struct Clock {
+    #[range(0..24)]
+    hour: i8,
+
+    #[range(0..60)]
+    minute: i8,
+
+    label: &'static str,
+}
+
+#[test]
+fn constructs() {
+    let clock = Clock::try_new(23, 59, "bedtime").unwrap();
+    assert_eq!(clock, Clock { hour: 23, minute: 59, label: "bedtime" });
+}
+
+#[test]
+fn reports_field() {
+    let err = Clock::try_new(23, 60, "never").unwrap_err();
+    assert_eq!(err.field(), "minute");
+    assert_eq!(err.error.outside_value, 60);
+    assert_eq!(err.error.allowed_range, Bounds::half_open(0, 60));
+}
+
+#[test]
+fn reports_first_field() {
+    let err = Clock::try_new(24, 60, "never").unwrap_err();
+    assert_eq!(err.field(), "hour");
+    assert_eq!(err.error.violation(), Violation::AboveUpper);
+}
+
+#[test]
+fn validates_after_mutation() {
+    let mut clock = Clock::try_new(12, 0, "noon").unwrap();
+    assert!(clock.validate().is_ok());
+
+    clock.hour = -1;
+    assert_eq!(clock.validate().unwrap_err().to_string(),
+               "hour: value (-1) below range (0..24)");
+}
+
+
+#[derive(RangeCheck, Debug)]
+#[range_check(error = i32)]
+struct Mixed {
+    #[range(0..=100)]
+    percent: u8,
+
+    #[range(-10..)]
+    #[range(..10)]
+    offset: i16,
+
+    #[range(1..)]
+    count: i32,
+}
+
+#[test]
+fn mixed_types() {
+    assert!(Mixed::try_new(100, 0, 1).is_ok());
+
+    let err: FieldError<i32> = Mixed::try_new(101, 0, 1).unwrap_err();
+    assert_eq!(err.field(), "percent");
+    assert_eq!(err.error.allowed_range, Bounds::closed(0, 100));
+}
+
+#[test]
+fn several_ranges_per_field() {
+    assert_eq!(Mixed::try_new(0, -11, 1).unwrap_err().error.allowed_range, Bounds::at_least(-10));
+    assert_eq!(Mixed::try_new(0, 10, 1).unwrap_err().error.allowed_range, Bounds { lower: Bound::Unbounded, upper: Bound::Excluded(10) });
+}
+
+
+#[derive(RangeCheck, Debug)]
+struct Rgb(#[range(0.0..=1.0)] f32, #[range(0.0..=1.0)] f32, #[range(0.0..=1.0)] f32);
+
+#[test]
+fn tuple_struct() {
+    assert!(Rgb::try_new(0.0, 0.5, 1.0).is_ok());
+
+    let err = Rgb::try_new(0.0, f32::NAN, 1.0).unwrap_err();
+    assert_eq!(err.field(), "1");
+    assert_eq!(err.error.kind, ErrorKind::Incomparable);
+}
+
+
+#[derive(RangeCheck, Debug)]
+struct Sample<T: Copy + PartialOrd + From<u8>> {
+    #[range(T::from(1) .. T::from(10))]
+    value: T,
+}
+
+#[test]
+fn generic_struct() {
+    assert!(Sample::try_new(5_u32).is_ok());
+    assert!(Sample::try_new(50.0_f64).is_err());
+}
@@ -0,0 +1,321 @@
+use std::cmp::Ordering;
+use std::ops::Bound;
+
+use bounds::Bounds;
+
+
+// Interval algebra for the Bounds type.
+//
+// Endpoints are compared as though they were positions on a line: an
+// unbounded lower endpoint is at negative infinity, an excluded lower endpoint
+// sits just *after* its value, an excluded upper endpoint sits just *before*
+// its value, and an unbounded upper endpoint is at positive infinity.
+
+impl<T: PartialOrd + Clone> Bounds<T> {
+
+    /// Returns the range of values that lie within both `self` and `other`,
+    /// or `None` if the two ranges do not overlap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_check::Bounds;
+    ///
+    /// let user_supplied = Bounds::at_least(50);
+    /// let hardware_limit = Bounds::closed(0, 100);
+    ///
+    /// assert_eq!(user_supplied.intersection(&hardware_limit),
+    ///            Some(Bounds::closed(50, 100)));
+    ///
+    /// assert_eq!(Bounds::half_open(0, 5).intersection(&Bounds::half_open(5, 10)),
+    ///            None);
+    /// ```
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let lower = match cmp_lowers(&self.lower, &other.lower) {
+            Ordering::Less  => &other.lower,
+            _               => &self.lower,
+        };
+
+        let upper = match cmp_uppers(&self.upper, &other.upper) {
+            Ordering::Greater  => &other.upper,
+            _                  => &self.upper,
+        };
+
+        let bounds = Bounds { lower: lower.clone(), upper: upper.clone() };
+        if bounds.is_empty() { None } else { Some(bounds) }
+    }
+
+    /// Returns whether there are any values that lie within both `self` and
+    /// `other`. This is the same as checking whether their `intersection`
+    /// exists, without cloning any values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_check::Bounds;
+    ///
+    /// assert!(Bounds::closed(0, 5).overlaps(&Bounds::closed(5, 10)));
+    /// assert!(!Bounds::half_open(0, 5).overlaps(&Bounds::closed(5, 10)));
+    /// ```
+    pub fn overlaps(&self, other: &Self) -> bool {
+        ! self.is_empty() && ! other.is_empty()
+            && ! ends_before(&self.upper, &other.lower)
+            && ! ends_before(&other.upper, &self.lower)
+    }
+
+    /// Returns the smallest range that covers both `self` and `other`,
+    /// including any gap between them. An empty or inverted range covers no
+    /// values, so if either range is one, the other is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_check::Bounds;
+    ///
+    /// assert_eq!(Bounds::half_open(0, 5).hull(&Bounds::closed(10, 20)),
+    ///            Bounds::closed(0, 20));
+    ///
+    /// assert_eq!(Bounds::half_open(0, 5).hull(&Bounds::at_least(3)),
+    ///            Bounds::at_least(0));
+    ///
+    /// assert_eq!(Bounds::closed(0, 1).hull(&Bounds::closed(10, 5)),
+    ///            Bounds::closed(0, 1));
+    /// ```
+    pub fn hull(&self, other: &Self) -> Self {
+        if other.is_empty() {
+            return self.clone();
+        }
+        else if self.is_empty() {
+            return other.clone();
+        }
+
+        let lower = match cmp_lowers(&self.lower, &other.lower) {
+            Ordering::Greater  => &other.lower,
+            _                  => &self.lower,
+        };
+
+        let upper = match cmp_uppers(&self.upper, &other.upper) {
+            Ordering::Less  => &other.upper,
+            _               => &self.upper,
+        };
+
+        Bounds { lower: lower.clone(), upper: upper.clone() }
+    }
+
+    /// Returns the ranges of values that lie within `self` but not within
+    /// `other`. There can be zero, one, or two of them: none if `other`
+    /// covers `self` completely, and two if `other` splits `self` in half.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_check::Bounds;
+    ///
+    /// assert_eq!(Bounds::closed(0, 10).difference(&Bounds::at_least(5)),
+    ///            vec![ Bounds::half_open(0, 5) ]);
+    ///
+    /// assert_eq!(Bounds::closed(0, 10).difference(&Bounds::open(3, 6)),
+    ///            vec![ Bounds::closed(0, 3), Bounds::closed(6, 10) ]);
+    ///
+    /// assert_eq!(Bounds::closed(0, 10).difference(&Bounds::unbounded()),
+    ///            vec![]);
+    /// ```
+    pub fn difference(&self, other: &Self) -> Vec<Self> {
+        if self.is_empty() {
+            return Vec::new();
+        }
+        else if other.is_empty() {
+            return vec![ self.clone() ];
+        }
+
+        other.complement().iter()
+             .filter_map(|piece| self.intersection(piece))
+             .collect()
+    }
+
+    /// Returns the ranges of values that do not lie within `self`. There can
+    /// be zero, one, or two of them, depending on how many of the bounds
+    /// are unbounded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_check::Bounds;
+    /// use std::ops::Bound;
+    ///
+    /// assert_eq!(Bounds::half_open(0, 10).complement(),
+    ///            vec![ Bounds { lower: Bound::Unbounded, upper: Bound::Excluded(0) },
+    ///                  Bounds::at_least(10) ]);
+    ///
+    /// assert_eq!(Bounds::at_most(10).complement(),
+    ///            vec![ Bounds { lower: Bound::Excluded(10), upper: Bound::Unbounded } ]);
+    ///
+    /// assert_eq!(Bounds::<i32>::unbounded().complement(),
+    ///            vec![]);
+    /// ```
+    pub fn complement(&self) -> Vec<Self> {
+        if self.is_empty() {
+            return vec![ Bounds::unbounded() ];
+        }
+
+        let mut pieces = Vec::new();
+
+        if let Some(upper) = flip_bound(&self.lower) {
+            pieces.push(Bounds { lower: Bound::Unbounded, upper });
+        }
+
+        if let Some(lower) = flip_bound(&self.upper) {
+            pieces.push(Bounds { lower, upper: Bound::Unbounded });
+        }
+
+        pieces
+    }
+}
+
+impl<T: PartialOrd> Bounds<T> {
+
+    /// Returns whether there are no values that lie within these bounds,
+    /// either because the range is inverted, or because it starts and ends
+    /// at the same value while excluding it.
+    ///
+    /// This treats the values as continuous, so ranges such as
+    /// `Bounds::open(1, 2)` are _not_ considered to be empty, even though
+    /// there are no integers between 1 and 2.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_check::Bounds;
+    ///
+    /// assert!(Bounds::half_open(5, 5).is_empty());
+    /// assert!(Bounds::closed(5, 3).is_empty());
+    /// assert!(!Bounds::closed(5, 5).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        match (&self.lower, &self.upper) {
+            (Bound::Unbounded, _) | (_, Bound::Unbounded)  => false,
+            (Bound::Included(l), Bound::Included(u))        => match l.partial_cmp(u) {
+                Some(Ordering::Less) | Some(Ordering::Equal) => false,
+                Some(Ordering::Greater) | None               => true,
+            },
+            (Bound::Included(l), Bound::Excluded(u)) |
+            (Bound::Excluded(l), Bound::Included(u)) |
+            (Bound::Excluded(l), Bound::Excluded(u))        => l.partial_cmp(u) != Some(Ordering::Less),
+        }
+    }
+
+    /// Returns whether exactly one value lies within these bounds, which is
+    /// the case when both bounds include the same value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_check::Bounds;
+    ///
+    /// assert!(Bounds::closed(5, 5).is_singleton());
+    /// assert!(!Bounds::half_open(5, 5).is_singleton());
+    /// assert!(!Bounds::closed(5, 6).is_singleton());
+    /// ```
+    pub fn is_singleton(&self) -> bool {
+        match (&self.lower, &self.upper) {
+            (Bound::Included(l), Bound::Included(u))  => l == u,
+            _                                         => false,
+        }
+    }
+
+    /// Returns whether the lower bound is greater than the upper bound, such
+    /// as with `5 .. 3`. Inverted ranges are always empty, and usually
+    /// indicate a bug in whatever created the range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_check::Bounds;
+    ///
+    /// assert!(Bounds::closed(5, 3).is_inverted());
+    /// assert!(!Bounds::half_open(5, 5).is_inverted());
+    /// assert!(!Bounds::at_least(5).is_inverted());
+    /// ```
+    pub fn is_inverted(&self) -> bool {
+        match (&self.lower, &self.upper) {
+            (Bound::Unbounded, _) | (_, Bound::Unbounded)  => false,
+            (Bound::Included(l), Bound::Included(u)) |
+            (Bound::Included(l), Bound::Excluded(u)) |
+            (Bound::Excluded(l), Bound::Included(u)) |
+            (Bound::Excluded(l), Bound::Excluded(u))        => l > u,
+        }
+    }
+}
+
+
+/// Compares two lower bounds by the position where they start.
+/// Incomparable values are treated as equal.
+pub(crate) fn cmp_lowers<T: PartialOrd>(a: &Bound<T>, b: &Bound<T>) -> Ordering {
+    match (a, b) {
+        (Bound::Unbounded, Bound::Unbounded)      => Ordering::Equal,
+        (Bound::Unbounded, _)                     => Ordering::Less,
+        (_, Bound::Unbounded)                     => Ordering::Greater,
+        (Bound::Included(x), Bound::Included(y))  |
+        (Bound::Excluded(x), Bound::Excluded(y))  => cmp_values(x, y),
+        (Bound::Included(x), Bound::Excluded(y))  => cmp_values(x, y).then(Ordering::Less),
+        (Bound::Excluded(x), Bound::Included(y))  => cmp_values(x, y).then(Ordering::Greater),
+    }
+}
+
+/// Compares two upper bounds by the position where they end.
+/// Incomparable values are treated as equal.
+pub(crate) fn cmp_uppers<T: PartialOrd>(a: &Bound<T>, b: &Bound<T>) -> Ordering {
+    match (a, b) {
+        (Bound::Unbounded, Bound::Unbounded)      => Ordering::Equal,
+        (Bound::Unbounded, _)                     => Ordering::Greater,
+        (_, Bound::Unbounded)                     => Ordering::Less,
+        (Bound::Included(x), Bound::Included(y))  |
+        (Bound::Excluded(x), Bound::Excluded(y))  => cmp_values(x, y),
+        (Bound::Included(x), Bound::Excluded(y))  => cmp_values(x, y).then(Ordering::Greater),
+        (Bound::Excluded(x), Bound::Included(y))  => cmp_values(x, y).then(Ordering::Less),
+    }
+}
+
+/// Whether a range with the given upper bound ends before a range with the
+/// given lower bound starts, leaving no values in common.
+pub(crate) fn ends_before<T: PartialOrd>(upper: &Bound<T>, lower: &Bound<T>) -> bool {
+    match (upper, lower) {
+        (Bound::Unbounded, _) | (_, Bound::Unbounded)  => false,
+        (Bound::Included(u), Bound::Included(l))        => u < l,
+        (Bound::Included(u), Bound::Excluded(l)) |
+        (Bound::Excluded(u), Bound::Included(l)) |
+        (Bound::Excluded(u), Bound::Excluded(l))        => u <= l,
+    }
+}
+
+/// Whether a range with the given lower bound starts at or before the value.
+pub(crate) fn lower_admits<T: PartialOrd>(lower: &Bound<T>, value: &T) -> bool {
+    match lower {
+        Bound::Unbounded    => true,
+        Bound::Included(l)  => l <= value,
+        Bound::Excluded(l)  => l < value,
+    }
+}
+
+/// Whether a range with the given upper bound ends at or after the value.
+pub(crate) fn upper_admits<T: PartialOrd>(upper: &Bound<T>, value: &T) -> bool {
+    match upper {
+        Bound::Unbounded    => true,
+        Bound::Included(u)  => value <= u,
+        Bound::Excluded(u)  => value < u,
+    }
+}
+
+fn cmp_values<T: PartialOrd>(x: &T, y: &T) -> Ordering {
+    x.partial_cmp(y).unwrap_or(Ordering::Equal)
+}
+
+/// Turns the bound at one end of a range into the bound at the opposite end
+/// of the range that lies next to it, or `None` if there is no such range.
+pub(crate) fn flip_bound<T: Clone>(bound: &Bound<T>) -> Option<Bound<T>> {
+    match *bound {
+        Bound::Included(ref n)  => Some(Bound::Excluded(n.clone())),
+        Bound::Excluded(ref n)  => Some(Bound::Included(n.clone())),
+        Bound::Unbounded        => None,
+    }
+}
@@ -0,0 +1,137 @@
+use std::any::{self, TypeId};
+use std::error::Error as ErrorTrait;
+use std::fmt;
+
+use check::{ErrorKind, OutOfRangeError, Violation};
+
+
+/// An `OutOfRangeError` with its value type erased, so that errors from
+/// checking values of different types can be returned from one function.
+///
+/// Rather than holding the value and range themselves, this holds them
+/// already rendered as strings, along with the `TypeId` and name of the
+/// original value type. Its `kind` is kept too, with any values in it
+/// rendered the same way. Any `OutOfRangeError` can be converted into one
+/// using `From`, so the `?` operator works with values of any type.
+///
+/// # Examples
+///
+/// ```
+/// use range_check::{AnyOutOfRangeError, Check};
+///
+/// fn check_settings(volume: f32, limit: u64, initial: char) -> Result<(), AnyOutOfRangeError> {
+///     volume.check_range(0.0 ..= 1.0)?;
+///     limit.check_range(1 .. 1000)?;
+///     initial.check_range('A' ..= 'Z')?;
+///     Ok(())
+/// }
+///
+/// assert!(check_settings(0.5, 10, 'Q').is_ok());
+///
+/// let err = check_settings(0.5, 10, 'q').unwrap_err();
+/// assert!(err.is::<char>());
+/// assert_eq!(err.outside_value(), "'q'");
+/// assert_eq!(err.allowed_range(), "'A'..='Z'");
+/// assert_eq!(err.to_string(), "value ('q') above range ('A'..='Z')");
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct AnyOutOfRangeError {
+
+    // The details are boxed to keep `Result`s holding this error small.
+    details: Box<Details>,
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+struct Details {
+    outside_value: String,
+    allowed_range: String,
+    violation: Violation,
+    kind: ErrorKind<String>,
+    path: Option<String>,
+    message: String,
+    type_id: TypeId,
+    type_name: &'static str,
+}
+
+impl AnyOutOfRangeError {
+
+    /// Returns the value that lies outside of the range, in its `Debug` form.
+    pub fn outside_value(&self) -> &str {
+        &self.details.outside_value
+    }
+
+    /// Returns the range that was searched, in its `Display` form.
+    pub fn allowed_range(&self) -> &str {
+        &self.details.allowed_range
+    }
+
+    /// Returns which side of the allowed range the value lies on.
+    pub fn violation(&self) -> Violation {
+        self.details.violation
+    }
+
+    /// Returns why the value does not lie within the range, with any values
+    /// in it in their `Debug` form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_check::{AnyOutOfRangeError, Check, ErrorKind};
+    ///
+    /// let err = AnyOutOfRangeError::from(4_u8.check_range(5..3).unwrap_err());
+    /// assert_eq!(err.kind(), &ErrorKind::InvertedRange);
+    /// ```
+    pub fn kind(&self) -> &ErrorKind<String> {
+        &self.details.kind
+    }
+
+    /// Returns the path to the value that was checked, if it had one.
+    pub fn path(&self) -> Option<&str> {
+        self.details.path.as_deref()
+    }
+
+    /// Returns the `TypeId` of the type of the value that was checked.
+    pub fn value_type_id(&self) -> TypeId {
+        self.details.type_id
+    }
+
+    /// Returns the name of the type of the value that was checked. As with
+    /// `std::any::type_name`, this is only meant for diagnostics.
+    pub fn type_name(&self) -> &'static str {
+        self.details.type_name
+    }
+
+    /// Returns whether the value that was checked was of type `T`.
+    pub fn is<T: 'static>(&self) -> bool {
+        self.details.type_id == TypeId::of::<T>()
+    }
+}
+
+impl<T: fmt::Debug + PartialOrd + 'static> From<OutOfRangeError<T>> for AnyOutOfRangeError {
+    fn from(error: OutOfRangeError<T>) -> Self {
+        let details = Details {
+            outside_value: format!("{:?}", error.outside_value),
+            allowed_range: error.allowed_range.to_string(),
+            violation: error.violation(),
+            message: error.to_string(),
+            kind: error.kind.map(|value| format!("{:?}", value)),
+            path: error.path,
+            type_id: TypeId::of::<T>(),
+            type_name: any::type_name::<T>(),
+        };
+
+        AnyOutOfRangeError { details: Box::new(details) }
+    }
+}
+
+impl fmt::Display for AnyOutOfRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.details.message)
+    }
+}
+
+impl ErrorTrait for AnyOutOfRangeError {
+    fn description(&self) -> &str {
+        "value outside of range"
+    }
+}
@@ -0,0 +1,105 @@
+use std::fmt;
+use std::ops::RangeBounds;
+
+use bounds::Bounds;
+use check::{ErrorKind, OutOfRangeError};
+
+
+/// Asserts that a value is within a range, panicking if it is not.
+///
+/// The panic message contains the expression that was checked, along with
+/// the value and the range it fell outside of, and the panic is reported at
+/// the location of the macro call. A custom message can be given after the
+/// range, using the same syntax as `format!`.
+///
+/// The value and range are only borrowed, so neither needs to be `Copy`.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate range_check;
+///
+/// # fn main() {
+/// let hour = 23;
+/// assert_in_range!(hour, 0..24);
+/// assert_in_range!(hour, 0..24, "bad hour in {}", "alarm");
+/// # }
+/// ```
+///
+/// ```should_panic
+/// #[macro_use] extern crate range_check;
+///
+/// # fn main() {
+/// let minute = 60;
+/// assert_in_range!(minute, 0..60);  // panics!
+/// # }
+/// ```
+#[macro_export]
+macro_rules! assert_in_range {
+    ($value:expr, $range:expr $(,)?) => {
+        match (&$value, &$range) {
+            (value, range) => {
+                if ! ::std::ops::RangeBounds::contains(range, value) {
+                    $crate::__assert_in_range_failed(stringify!($value), value, range, None);
+                }
+            }
+        }
+    };
+    ($value:expr, $range:expr, $($arg:tt)+) => {
+        match (&$value, &$range) {
+            (value, range) => {
+                if ! ::std::ops::RangeBounds::contains(range, value) {
+                    $crate::__assert_in_range_failed(stringify!($value), value, range, Some(format_args!($($arg)+)));
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that a value is within a range, but only in builds with debug
+/// assertions enabled, like the standard library’s `debug_assert!`.
+///
+/// In release builds, neither the value nor the range is evaluated, so this
+/// can be used to check invariants in hot loops at no cost.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate range_check;
+///
+/// # fn main() {
+/// let samples = [ 3, 1, 4, 1, 5 ];
+/// for sample in &samples {
+///     debug_assert_in_range!(*sample, 0..10);
+/// }
+/// # }
+/// ```
+#[macro_export]
+macro_rules! debug_assert_in_range {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_in_range!($($arg)*);
+        }
+    };
+}
+
+
+/// Panics with the message for a failed `assert_in_range!`. The panic is
+/// reported at the location of the macro call.
+#[doc(hidden)]
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub fn assert_failed<T, R>(expr: &str, value: &T, range: &R, message: Option<fmt::Arguments>) -> !
+where T: fmt::Debug + PartialOrd + ?Sized,
+      R: RangeBounds<T> + ?Sized,
+{
+    let bounds = Bounds { lower: range.start_bound(), upper: range.end_bound() };
+    let kind = ErrorKind::of_failed_check(&bounds, &value);
+    let error = OutOfRangeError::new(bounds, value, kind);
+
+    match message {
+        Some(message)  => panic!("assertion failed: `{}` in range: {}: {}", expr, error, message),
+        None           => panic!("assertion failed: `{}` in range: {}", expr, error),
+    }
+}
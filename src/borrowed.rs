@@ -0,0 +1,86 @@
+use std::borrow::ToOwned;
+use std::ops::RangeBounds;
+
+use bounds::Bounds;
+use check::{ErrorKind, OutOfRangeError};
+
+
+/// Trait for range-checking values by reference, so that values that are
+/// not `Copy`, such as `String` or big number types, can be checked too.
+///
+/// The error borrows both the value and the range. To keep the error
+/// around for longer, turn it into one that holds owned copies using
+/// `OutOfRangeError::into_owned`.
+///
+/// Unsized values can be checked too, such as a `str` borrowed from a
+/// `String`, against a range made from a pair of borrowed `Bound`s.
+///
+/// # Examples
+///
+/// ```
+/// use range_check::CheckRef;
+/// use std::ops::Bound;
+///
+/// let start = String::from("A");
+/// let end = String::from("N");
+///
+/// let name = String::from("Marmoset");
+/// assert_eq!(name.check_range_ref(&(start .. end)), Ok(&name));
+///
+/// let first_half = (Bound::Included("A"), Bound::Excluded("N"));
+/// let err = "Tamarin".check_range_ref(&first_half).unwrap_err();
+/// assert_eq!(err.to_string(), r#"value ("Tamarin") above range ("A".."N")"#);
+/// ```
+pub trait CheckRef: PartialOrd {
+
+    /// Checks whether `self` is within the given range. If it is, re-returns
+    /// a reference to `self`. Otherwise, returns an `Error` that contains
+    /// references to both the value and the range’s bounds.
+    fn check_range_ref<'a, R>(&'a self, range: &'a R) -> Result<&'a Self, OutOfRangeError<&'a Self>>
+    where R: RangeBounds<Self>;
+}
+
+impl<T: PartialOrd + ?Sized> CheckRef for T {
+    fn check_range_ref<'a, R>(&'a self, range: &'a R) -> Result<&'a Self, OutOfRangeError<&'a Self>>
+    where R: RangeBounds<Self>
+    {
+        if range.contains(self) {
+            Ok(self)
+        }
+        else {
+            let bounds = Bounds { lower: range.start_bound(), upper: range.end_bound() };
+            let kind = ErrorKind::of_failed_check(&bounds, &self);
+            Err(OutOfRangeError::new(bounds, self, kind))
+        }
+    }
+}
+
+impl<T: ToOwned + ?Sized> OutOfRangeError<&T> {
+
+    /// Converts an error that borrows its value and range into one that
+    /// owns them, by cloning them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_check::{Bounds, CheckRef, OutOfRangeError};
+    ///
+    /// fn check_word(word: &str) -> Result<(), OutOfRangeError<String>> {
+    ///     let range = Bounds::half_open(String::from("a"), String::from("n"));
+    ///     word.to_owned().check_range_ref(&range).map_err(OutOfRangeError::into_owned)?;
+    ///     Ok(())
+    /// }
+    ///
+    /// let err = check_word("zebra").unwrap_err();
+    /// assert_eq!(err.outside_value, "zebra");
+    /// assert_eq!(err.allowed_range, Bounds::half_open(String::from("a"), String::from("n")));
+    /// ```
+    pub fn into_owned(self) -> OutOfRangeError<T::Owned> {
+        OutOfRangeError {
+            allowed_range: self.allowed_range.map(ToOwned::to_owned),
+            outside_value: self.outside_value.to_owned(),
+            kind: self.kind.map(ToOwned::to_owned),
+            path: self.path,
+        }
+    }
+}
@@ -0,0 +1,182 @@
+use std::convert::TryFrom;
+use std::fmt;
+use std::ops::Deref;
+
+use bounds::Bounds;
+use check::{Check, OutOfRangeError};
+
+
+/// An integer that is guaranteed to lie between `MIN` and `MAX`, inclusive.
+///
+/// The value is range-checked once when the `Bounded` is created, so any
+/// code that takes a `Bounded` can rely on it being within range without
+/// checking it again. Arithmetic on it never leaves the range.
+///
+/// The bounds are given as `i128` values, so that one type works for every
+/// integer type. Bounds that lie beyond what the integer type can hold are
+/// treated as the integer type’s own minimum or maximum, and a range that
+/// lies entirely beyond it is empty, so no value can be created. This is
+/// implemented for the primitive integer types up to 64 bits wide.
+///
+/// # Examples
+///
+/// ```
+/// use range_check::Bounded;
+///
+/// type Hour = Bounded<u8, 0, 23>;
+///
+/// let hour = Hour::new(22).unwrap();
+/// assert_eq!(*hour, 22);
+///
+/// assert!(Hour::new(24).is_err());
+///
+/// assert_eq!(hour.checked_add(5), None);
+/// assert_eq!(*hour.saturating_add(5), 23);
+/// assert_eq!(*hour.wrapping_add(5), 3);
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone)]
+pub struct Bounded<T, const MIN: i128, const MAX: i128>(T);
+
+impl<T, const MIN: i128, const MAX: i128> Bounded<T, MIN, MAX> {
+
+    /// Returns the integer inside this wrapper.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T, const MIN: i128, const MAX: i128> Deref for Bounded<T, MIN, MAX> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: fmt::Debug, const MIN: i128, const MAX: i128> fmt::Debug for Bounded<T, MIN, MAX> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: fmt::Display, const MIN: i128, const MAX: i128> fmt::Display for Bounded<T, MIN, MAX> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+
+macro_rules! impl_bounded {
+    ($($t:ty),*) => {
+        $(
+            impl<const MIN: i128, const MAX: i128> Bounded<$t, MIN, MAX> {
+
+                /// Creates a new bounded integer, checking that the value is
+                /// within the bounds.
+                pub fn new(value: $t) -> Result<Self, OutOfRangeError<$t>> {
+                    value.check_range(Self::range()).map(Bounded)
+                }
+
+                /// Returns the range of values this type can hold.
+                pub fn range() -> Bounds<$t> {
+                    if MIN > <$t>::MAX as i128 {
+                        Bounds::half_open(<$t>::MAX, <$t>::MAX)
+                    }
+                    else if MAX < <$t>::MIN as i128 {
+                        Bounds::half_open(<$t>::MIN, <$t>::MIN)
+                    }
+                    else {
+                        Bounds::closed(Self::lowest() as $t, Self::highest() as $t)
+                    }
+                }
+
+                fn lowest() -> i128 {
+                    MIN.clamp(<$t>::MIN as i128, <$t>::MAX as i128)
+                }
+
+                fn highest() -> i128 {
+                    MAX.clamp(<$t>::MIN as i128, <$t>::MAX as i128)
+                }
+
+                /// Adds to this value, returning `None` if the result is out
+                /// of range.
+                pub fn checked_add(self, rhs: $t) -> Option<Self> {
+                    self.checked(self.0 as i128 + rhs as i128)
+                }
+
+                /// Subtracts from this value, returning `None` if the result
+                /// is out of range.
+                pub fn checked_sub(self, rhs: $t) -> Option<Self> {
+                    self.checked(self.0 as i128 - rhs as i128)
+                }
+
+                /// Adds to this value, stopping at the upper bound.
+                pub fn saturating_add(self, rhs: $t) -> Self {
+                    self.saturating(self.0 as i128 + rhs as i128)
+                }
+
+                /// Subtracts from this value, stopping at the lower bound.
+                pub fn saturating_sub(self, rhs: $t) -> Self {
+                    self.saturating(self.0 as i128 - rhs as i128)
+                }
+
+                /// Adds to this value, wrapping around to the lower bound
+                /// when going past the upper bound.
+                pub fn wrapping_add(self, rhs: $t) -> Self {
+                    self.wrapping(self.0 as i128 + rhs as i128)
+                }
+
+                /// Subtracts from this value, wrapping around to the upper
+                /// bound when going past the lower bound.
+                pub fn wrapping_sub(self, rhs: $t) -> Self {
+                    self.wrapping(self.0 as i128 - rhs as i128)
+                }
+
+                // The arithmetic is done in i128, which can hold the result
+                // of adding or subtracting any two 64-bit integers.
+
+                fn checked(self, result: i128) -> Option<Self> {
+                    if result >= Self::lowest() && result <= Self::highest() {
+                        Some(Bounded(result as $t))
+                    }
+                    else {
+                        None
+                    }
+                }
+
+                fn saturating(self, result: i128) -> Self {
+                    Bounded(result.max(Self::lowest()).min(Self::highest()) as $t)
+                }
+
+                fn wrapping(self, result: i128) -> Self {
+                    let lowest = Self::lowest();
+                    let period = Self::highest() - lowest + 1;
+
+                    // A value can only exist if the range has values in it,
+                    // but guard against an empty range all the same.
+                    if period <= 0 {
+                        return self;
+                    }
+
+                    Bounded((lowest + (result - lowest).rem_euclid(period)) as $t)
+                }
+            }
+
+            impl<const MIN: i128, const MAX: i128> TryFrom<$t> for Bounded<$t, MIN, MAX> {
+                type Error = OutOfRangeError<$t>;
+
+                fn try_from(value: $t) -> Result<Self, Self::Error> {
+                    Self::new(value)
+                }
+            }
+
+            impl<const MIN: i128, const MAX: i128> From<Bounded<$t, MIN, MAX>> for $t {
+                fn from(bounded: Bounded<$t, MIN, MAX>) -> $t {
+                    bounded.0
+                }
+            }
+        )*
+    };
+}
+
+impl_bounded! { i8, i16, i32, i64, isize, u8, u16, u32, u64, usize }
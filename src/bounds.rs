@@ -1,11 +1,29 @@
 use std::fmt;
-use std::ops::Bound;
+use std::ops::{Bound, RangeBounds};
+
+use format::BoundsFormat;
 
 
 // We need this type to generalise over all the Range types.
 
 /// The two bounds destructured from a Range value.
-#[derive(PartialEq, Debug, Clone)]
+///
+/// A `Bounds` can be created from any of Rust’s `Range` types, or built
+/// directly using one of its constructors. It implements `RangeBounds`, so it
+/// can be handed straight back to `check_range`.
+///
+/// # Examples
+///
+/// ```
+/// use range_check::{Bounds, Check};
+///
+/// let hours = Bounds::half_open(0, 24);
+/// assert!(hours.contains(&23));
+/// assert!(!hours.contains(&24));
+///
+/// assert_eq!(23.check_range(hours), Ok(23));
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone)]
 pub struct Bounds<T> {
 
     /// The lower bound, created by `start_bound`.
@@ -17,44 +35,211 @@ pub struct Bounds<T> {
 
 impl<T: fmt::Debug> fmt::Display for Bounds<T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match &self.lower {
-            Bound::Included(n)  => write!(f, "{:?}", n)?,
-            Bound::Excluded(n)  => write!(f, "{:?}=", n)?,  // surprisingly, not unreachable
-            Bound::Unbounded    => {},
-        }
+        BoundsFormat::Rust.write(f, self)
+    }
+}
+
+impl<T> Bounds<T> {
+
+    /// Creates a new range that includes both its lower and upper bounds,
+    /// like `lower ..= upper`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_check::Bounds;
+    ///
+    /// let bounds = Bounds::closed(1, 5);
+    /// assert!(bounds.contains(&1));
+    /// assert!(bounds.contains(&5));
+    /// ```
+    pub fn closed(lower: T, upper: T) -> Self {
+        Bounds { lower: Bound::Included(lower), upper: Bound::Included(upper) }
+    }
+
+    /// Creates a new range that excludes both its lower and upper bounds.
+    /// There is no Rust syntax for this range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_check::Bounds;
+    ///
+    /// let bounds = Bounds::open(1, 5);
+    /// assert!(!bounds.contains(&1));
+    /// assert!(bounds.contains(&4));
+    /// assert!(!bounds.contains(&5));
+    /// ```
+    pub fn open(lower: T, upper: T) -> Self {
+        Bounds { lower: Bound::Excluded(lower), upper: Bound::Excluded(upper) }
+    }
+
+    /// Creates a new range that includes its lower bound but excludes its
+    /// upper bound, like `lower .. upper`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_check::Bounds;
+    ///
+    /// let bounds = Bounds::half_open(1, 5);
+    /// assert!(bounds.contains(&1));
+    /// assert!(!bounds.contains(&5));
+    /// ```
+    pub fn half_open(lower: T, upper: T) -> Self {
+        Bounds { lower: Bound::Included(lower), upper: Bound::Excluded(upper) }
+    }
+
+    /// Creates a new range that includes its lower bound and has no upper
+    /// bound, like `lower ..`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_check::Bounds;
+    ///
+    /// let bounds = Bounds::at_least(1);
+    /// assert!(!bounds.contains(&0));
+    /// assert!(bounds.contains(&1));
+    /// ```
+    pub fn at_least(lower: T) -> Self {
+        Bounds { lower: Bound::Included(lower), upper: Bound::Unbounded }
+    }
+
+    /// Creates a new range that has no lower bound and includes its upper
+    /// bound, like `..= upper`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_check::Bounds;
+    ///
+    /// let bounds = Bounds::at_most(5);
+    /// assert!(bounds.contains(&5));
+    /// assert!(!bounds.contains(&6));
+    /// ```
+    pub fn at_most(upper: T) -> Self {
+        Bounds { lower: Bound::Unbounded, upper: Bound::Included(upper) }
+    }
 
-        write!(f, "..")?;
+    /// Creates a new range with no bounds at all, like `..`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_check::Bounds;
+    ///
+    /// let bounds = Bounds::unbounded();
+    /// assert!(bounds.contains(&i32::MIN));
+    /// assert!(bounds.contains(&i32::MAX));
+    /// ```
+    pub fn unbounded() -> Self {
+        Bounds { lower: Bound::Unbounded, upper: Bound::Unbounded }
+    }
 
-        match &self.upper {
-            Bound::Included(n)  => write!(f, "={:?}", n)?,
-            Bound::Excluded(n)  => write!(f, "{:?}", n)?,
-            Bound::Unbounded    => {},
+    /// Creates a new range by cloning the bounds out of any value that
+    /// implements `RangeBounds`, such as one of the standard `Range` types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_check::Bounds;
+    /// use std::ops::Bound;
+    ///
+    /// assert_eq!(Bounds::from_range_bounds(&(1 ..= 5)),
+    ///            Bounds::closed(1, 5));
+    ///
+    /// assert_eq!(Bounds::from_range_bounds(&(.. 5)),
+    ///            Bounds { lower: Bound::Unbounded, upper: Bound::Excluded(5) });
+    /// ```
+    pub fn from_range_bounds<R>(range: &R) -> Self
+    where R: RangeBounds<T> + ?Sized,
+          T: Clone,
+    {
+        Bounds {
+            lower: clone_bound(range.start_bound()),
+            upper: clone_bound(range.end_bound()),
         }
+    }
 
-        Ok(())
+    /// Returns whether the given value lies within these bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_check::Bounds;
+    ///
+    /// let bounds = Bounds::at_least(1);
+    /// assert!(bounds.contains(&1));
+    /// assert!(!bounds.contains(&0));
+    /// ```
+    pub fn contains(&self, value: &T) -> bool
+    where T: PartialOrd
+    {
+        RangeBounds::contains(self, value)
     }
-}
 
-impl<T> Bounds<T>
-{
-    // This is basically an implementation of From in all but name.
-    pub(crate) fn convert<U>(self) -> Bounds<U>
-    where U: From<T>
+    /// Converts both endpoints using the given function.
+    pub(crate) fn map<U, F>(self, mut f: F) -> Bounds<U>
+    where F: FnMut(T) -> U
     {
         let lower = match self.lower {
-            Bound::Included(t)  => Bound::Included(U::from(t)),
-            Bound::Excluded(t)  => Bound::Excluded(U::from(t)),
+            Bound::Included(t)  => Bound::Included(f(t)),
+            Bound::Excluded(t)  => Bound::Excluded(f(t)),
             Bound::Unbounded    => Bound::Unbounded,
         };
 
         let upper = match self.upper {
-            Bound::Included(t)  => Bound::Included(U::from(t)),
-            Bound::Excluded(t)  => Bound::Excluded(U::from(t)),
+            Bound::Included(t)  => Bound::Included(f(t)),
+            Bound::Excluded(t)  => Bound::Excluded(f(t)),
             Bound::Unbounded    => Bound::Unbounded,
         };
 
         Bounds { lower, upper }
     }
+
+    // This is basically an implementation of From in all but name.
+    pub(crate) fn convert<U>(self) -> Bounds<U>
+    where U: From<T>
+    {
+        self.map(U::from)
+    }
+}
+
+impl<T> RangeBounds<T> for Bounds<T> {
+    fn start_bound(&self) -> Bound<&T> {
+        ref_bound(&self.lower)
+    }
+
+    fn end_bound(&self) -> Bound<&T> {
+        ref_bound(&self.upper)
+    }
+}
+
+impl<T> RangeBounds<T> for &Bounds<T> {
+    fn start_bound(&self) -> Bound<&T> {
+        ref_bound(&self.lower)
+    }
+
+    fn end_bound(&self) -> Bound<&T> {
+        ref_bound(&self.upper)
+    }
+}
+
+pub(crate) fn ref_bound<T>(bound: &Bound<T>) -> Bound<&T> {
+    match *bound {
+        Bound::Unbounded        => Bound::Unbounded,
+        Bound::Included(ref n)  => Bound::Included(n),
+        Bound::Excluded(ref n)  => Bound::Excluded(n),
+    }
+}
+
+pub(crate) fn clone_bound<T: Clone>(bound: Bound<&T>) -> Bound<T> {
+    match bound {
+        Bound::Unbounded    => Bound::Unbounded,
+        Bound::Included(n)  => Bound::Included(n.clone()),
+        Bound::Excluded(n)  => Bound::Excluded(n.clone()),
+    }
 }
 
 // http://github.com/rust-lang/rust/issues/61356
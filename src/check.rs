@@ -1,18 +1,29 @@
 use std::error::Error as ErrorTrait;
-use std::ops::RangeBounds;
 use std::fmt;
+use std::ops::{Bound, RangeBounds};
 
-use bounds::{Bounds, copy_bound};
+use algebra::{lower_admits, upper_admits};
+use bounds::{Bounds, ref_bound};
+use field::join_path;
+use set::write_ranges;
+use custom::RangeError;
+use target::RangeTarget;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 
 /// Trait that provides early returns for failed range checks using the
 /// `Result` type.
-pub trait Check<R: RangeBounds<Self>>: Sized + PartialOrd + Copy {
+pub trait Check<R>: Sized + PartialOrd + Copy {
 
     /// Checks whether `self` is within the given range. If it is, re-returns
     /// `self`. Otherwise, returns an `Error` that contains both the value and
     /// the range.
     ///
+    /// The range can be one of the standard `Range` types, a `Bounds`, a
+    /// `RangeSet`, or any other `RangeTarget`.
+    ///
     /// # Examples
     ///
     /// ```
@@ -22,30 +33,151 @@ pub trait Check<R: RangeBounds<Self>>: Sized + PartialOrd + Copy {
     /// assert!(24680.check_range(1..9999).is_err());
     /// ```
     fn check_range(self, range: R) -> Result<Self, OutOfRangeError<Self>>;
+
+    /// Checks whether `self` is within the given range, like `check_range`,
+    /// but with the name of the value stored in the error’s path, so it can
+    /// be told apart from other values once it has been passed up.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_check::Check;
+    ///
+    /// assert_eq!(60.check_range_named("minute", 0..60).unwrap_err().to_string(),
+    ///            "minute: value (60) above range (0..60)");
+    /// ```
+    fn check_range_named(self, name: &str, range: R) -> Result<Self, OutOfRangeError<Self>> {
+        self.check_range(range).map_err(|e| e.with_path(name))
+    }
+
+    /// Checks whether `self` is within the given range, returning the given
+    /// error instead of an `OutOfRangeError` if it is not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_check::Check;
+    ///
+    /// #[derive(PartialEq, Debug)]
+    /// enum ClockError { BadHour, BadMinute }
+    ///
+    /// assert_eq!(24.check_range_or(0..24, ClockError::BadHour), Err(ClockError::BadHour));
+    /// ```
+    fn check_range_or<E>(self, range: R, error: E) -> Result<Self, E> {
+        self.check_range(range).map_err(|_| error)
+    }
+
+    /// Checks whether `self` is within the given range, turning the
+    /// `OutOfRangeError` into another error using the given function if it
+    /// is not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_check::{Check, Violation};
+    ///
+    /// #[derive(PartialEq, Debug)]
+    /// enum VolumeError { TooQuiet, TooLoud, Invalid }
+    ///
+    /// fn check_volume(volume: f32) -> Result<f32, VolumeError> {
+    ///     volume.check_range_with(0.0 ..= 11.0, |e| match e.violation() {
+    ///         Violation::BelowLower  => VolumeError::TooQuiet,
+    ///         Violation::AboveUpper  => VolumeError::TooLoud,
+    ///         _                      => VolumeError::Invalid,
+    ///     })
+    /// }
+    ///
+    /// assert_eq!(check_volume(12.0), Err(VolumeError::TooLoud));
+    /// assert_eq!(check_volume(f32::NAN), Err(VolumeError::Invalid));
+    /// ```
+    fn check_range_with<E, F>(self, range: R, f: F) -> Result<Self, E>
+    where F: FnOnce(OutOfRangeError<Self>) -> E
+    {
+        self.check_range(range).map_err(f)
+    }
+
+    /// Checks whether `self` is within the given range, creating an error of
+    /// a type that implements `RangeError` if it is not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_check::{AnyOutOfRangeError, Check};
+    ///
+    /// let err = 24.check_range_as::<AnyOutOfRangeError>(0..24).unwrap_err();
+    /// assert_eq!(err.outside_value(), "24");
+    /// ```
+    fn check_range_as<E>(self, range: R) -> Result<Self, E>
+    where E: RangeError<Self>
+    {
+        self.check_range(range).map_err(E::from_range_error)
+    }
+
+    /// Returns whether `self` is below, within, or above the given range,
+    /// without creating an error. Returns `None` if `self` cannot be compared
+    /// with the bounds of the range, such as when it is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_check::{Check, Position};
+    ///
+    /// assert_eq!(5.position_in_range(1..10), Some(Position::Within));
+    /// assert_eq!(0.position_in_range(1..10), Some(Position::Below));
+    /// assert_eq!(10.position_in_range(1..10), Some(Position::Above));
+    /// assert_eq!(f64::NAN.position_in_range(1.0..10.0), None);
+    /// ```
+    fn position_in_range(self, range: R) -> Option<Position>
+    where R: RangeBounds<Self>
+    {
+        if ! is_comparable(&self, range.start_bound()) || ! is_comparable(&self, range.end_bound()) {
+            None
+        }
+        else if ! lower_admits(&range.start_bound(), &&self) {
+            Some(Position::Below)
+        }
+        else if ! upper_admits(&range.end_bound(), &&self) {
+            Some(Position::Above)
+        }
+        else {
+            Some(Position::Within)
+        }
+    }
+}
+
+/// Whether the value can be compared with itself and with the bound’s value.
+pub(crate) fn is_comparable<T: PartialOrd>(value: &T, bound: Bound<&T>) -> bool {
+    let comparable_with_self = value.partial_cmp(value).is_some();
+
+    match bound {
+        Bound::Included(n) | Bound::Excluded(n)  => comparable_with_self && value.partial_cmp(n).is_some(),
+        Bound::Unbounded                         => comparable_with_self,
+    }
 }
 
 impl<T, R> Check<R> for T
-where R: RangeBounds<T>,
+where R: RangeTarget<T>,
       T: PartialOrd + Copy,
 {
     fn check_range(self, range: R) -> Result<Self, OutOfRangeError<Self>> {
-        if range.contains(&self) {
+        if range.contains_value(&self) {
             Ok(self)
         }
         else {
-            let bounds = Bounds {
-                lower: copy_bound(range.start_bound()),
-                upper: copy_bound(range.end_bound()),
-            };
-
-            Err(OutOfRangeError { allowed_range: bounds, outside_value: self })
+            Err(range.into_error(self))
         }
     }
 }
 
 
 /// The error that gets thrown when a `check_range` fails.
+///
+/// Its message says which side of the range the value lies on, so its
+/// `Display` and `Error` implementations need the value type to implement
+/// `PartialOrd` as well as `Debug`. Before version 0.3, they only needed
+/// `Debug`.
 #[derive(PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct OutOfRangeError<T> {
 
     /// The bounds of the range that was searched.
@@ -53,23 +185,279 @@ pub struct OutOfRangeError<T> {
 
     /// The value that lies outside of the range.
     pub outside_value: T,
+
+    /// Why the value does not lie within the range.
+    pub kind: ErrorKind<T>,
+
+    /// The path to the value that was checked, such as
+    /// `clock.alarms[2].minute`, if one was given with `check_range_named`
+    /// or `with_path`.
+    pub path: Option<String>,
+}
+
+
+/// Which side of a range a value lies on, as returned by
+/// `OutOfRangeError::violation`.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum Violation {
+
+    /// The value lies below the lower bound of the range.
+    BelowLower,
+
+    /// The value lies above the upper bound of the range.
+    AboveUpper,
+
+    /// The value lies between the bounds, but was still not allowed. This
+    /// happens when it falls in a gap between the ranges in a `RangeSet`.
+    InGap,
+
+    /// The value could not be compared with the bounds of the range, such
+    /// as when it is NaN.
+    Incomparable,
+
+    /// The value was checked against a `RangeSet` with no ranges in it, so
+    /// there was nowhere for it to lie.
+    EmptySet,
+}
+
+/// Where a value lies relative to a range, as returned by
+/// `Check::position_in_range`.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum Position {
+
+    /// The value lies below the lower bound of the range.
+    Below,
+
+    /// The value lies within the range.
+    Within,
+
+    /// The value lies above the upper bound of the range.
+    Above,
+}
+
+
+/// The reason that a `check_range` failed.
+///
+/// Usually, the value lies outside of a perfectly good range, but if the range
+/// itself has no values in it, then no value could ever pass the check. This
+/// is reported separately, as it’s the range that’s wrong, not the value.
+/// Values that cannot be compared with the range at all, such as NaN, are
+/// also reported separately.
+///
+/// When checking against a `RangeSet`, every range in the set is kept, as the
+/// `allowed_range` can only hold the smallest range that covers all of them.
+///
+/// # Examples
+///
+/// ```
+/// use range_check::{Check, ErrorKind};
+///
+/// assert_eq!(4.check_range(1..3).unwrap_err().kind, ErrorKind::Outside);
+/// assert_eq!(4.check_range(5..3).unwrap_err().kind, ErrorKind::InvertedRange);
+/// assert_eq!(4.check_range(4..4).unwrap_err().kind, ErrorKind::EmptyRange);
+/// assert_eq!(f64::NAN.check_range(0.0..1.0).unwrap_err().kind, ErrorKind::Incomparable);
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(rename_all = "snake_case"))]
+pub enum ErrorKind<T> {
+
+    /// The value lies outside of the range.
+    Outside,
+
+    /// The range has no values in it, as it starts and ends at the same
+    /// value while excluding it, such as `4 .. 4`.
+    EmptyRange,
+
+    /// The range has no values in it, as its lower bound is greater than its
+    /// upper bound, such as `5 .. 3`.
+    InvertedRange,
+
+    /// The value cannot be compared with the bounds of the range, so it is
+    /// neither inside nor outside of it. This happens with NaN floats. To
+    /// check floats using a total order instead, see `TotalOrder`.
+    Incomparable,
+
+    /// The value lies outside of every range in a `RangeSet`. This holds
+    /// the ranges in the set, which is empty if the set was.
+    OutsideSet(Vec<Bounds<T>>),
 }
 
-impl<T: fmt::Debug> ErrorTrait for OutOfRangeError<T> {
+impl<T> ErrorKind<T> {
+
+    /// Works out why a value failed to lie within the given bounds.
+    pub(crate) fn of_failed_check(bounds: &Bounds<T>, value: &T) -> Self
+    where T: PartialOrd + Clone
+    {
+        if ! is_comparable(value, ref_bound(&bounds.lower))
+        || ! is_comparable(value, ref_bound(&bounds.upper)) {
+            ErrorKind::Incomparable
+        }
+        else if bounds.is_inverted() {
+            ErrorKind::InvertedRange
+        }
+        else if bounds.is_empty() {
+            ErrorKind::EmptyRange
+        }
+        else {
+            ErrorKind::Outside
+        }
+    }
+
+    pub(crate) fn map<U, F>(self, mut f: F) -> ErrorKind<U>
+    where F: FnMut(T) -> U
+    {
+        match self {
+            ErrorKind::Outside            => ErrorKind::Outside,
+            ErrorKind::EmptyRange         => ErrorKind::EmptyRange,
+            ErrorKind::InvertedRange      => ErrorKind::InvertedRange,
+            ErrorKind::Incomparable       => ErrorKind::Incomparable,
+            ErrorKind::OutsideSet(ranges) => ErrorKind::OutsideSet(ranges.into_iter().map(|r| r.map(&mut f)).collect()),
+        }
+    }
+
+    fn convert<U: From<T>>(self) -> ErrorKind<U> {
+        self.map(U::from)
+    }
+}
+
+impl<T: fmt::Debug + PartialOrd> ErrorTrait for OutOfRangeError<T> {
     fn description(&self) -> &str {
         "value outside of range"
     }
 }
 
-impl<T: fmt::Debug> fmt::Display for OutOfRangeError<T> {
+impl<T: fmt::Debug + PartialOrd> fmt::Display for OutOfRangeError<T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "value ({:?}) outside of range ({})",
-            self.outside_value, self.allowed_range)
+        self.write_message(f, &|f, range| write!(f, "{}", range))
+    }
+}
+
+impl<T: fmt::Debug + PartialOrd> OutOfRangeError<T> {
+
+    /// Writes the message for this error, using the given function to
+    /// write the range or ranges in it.
+    pub(crate) fn write_message(&self, f: &mut fmt::Formatter, write_range: &dyn Fn(&mut fmt::Formatter, &Bounds<T>) -> fmt::Result) -> fmt::Result {
+        write_message(f, self.path.as_deref(), &self.outside_value, &self.allowed_range, &self.kind, self.violation(), write_range)
+    }
+}
+
+/// Writes the message for an error with the given parts, using the given
+/// function to write the range or ranges in it. The value and the range can
+/// have different types, so errors from `check_range_mixed` use this too.
+pub(crate) fn write_message<V: fmt::Debug, U>(f: &mut fmt::Formatter, path: Option<&str>, value: &V, allowed_range: &Bounds<U>, kind: &ErrorKind<U>, violation: Violation, write_range: &dyn Fn(&mut fmt::Formatter, &Bounds<U>) -> fmt::Result) -> fmt::Result {
+    let side = match violation {
+        Violation::BelowLower    => "below",
+        Violation::AboveUpper    => "above",
+        Violation::InGap         => "between",
+        Violation::Incomparable  => "incomparable with",
+        Violation::EmptySet      => "outside",
+    };
+
+    if let Some(path) = path.filter(|path| ! path.is_empty()) {
+        write!(f, "{}: ", path)?;
+    }
+
+    match kind {
+        ErrorKind::Outside => {
+            write!(f, "value ({:?}) {} range (", value, side)?;
+            write_range(f, allowed_range)?;
+            write!(f, ")")
+        }
+        ErrorKind::EmptyRange => {
+            write!(f, "range (")?;
+            write_range(f, allowed_range)?;
+            write!(f, ") is empty, so cannot contain value ({:?})", value)
+        }
+        ErrorKind::InvertedRange => {
+            write!(f, "range (")?;
+            write_range(f, allowed_range)?;
+            write!(f, ") is inverted, so cannot contain value ({:?})", value)
+        }
+        ErrorKind::Incomparable => {
+            write!(f, "value ({:?}) cannot be compared with range (", value)?;
+            write_range(f, allowed_range)?;
+            write!(f, ")")
+        }
+        ErrorKind::OutsideSet(ranges) if ranges.is_empty() => {
+            write!(f, "value ({:?}) outside of empty range set", value)
+        }
+        ErrorKind::OutsideSet(ranges) => {
+            write!(f, "value ({:?}) {} ranges (", value, side)?;
+            write_ranges(f, ranges, write_range)?;
+            write!(f, ")")
+        }
     }
 }
 
 impl<T> OutOfRangeError<T> {
 
+    /// Creates a new error for a value that does not lie within the given
+    /// range, without a path.
+    pub fn new(allowed_range: Bounds<T>, outside_value: T, kind: ErrorKind<T>) -> Self {
+        OutOfRangeError { allowed_range, outside_value, kind, path: None }
+    }
+
+    /// Adds a field name or index to the start of the path to the value,
+    /// for when the error is passed up to the code that checked the value
+    /// containing it. Indices such as `[2]` are joined without a dot.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_check::Check;
+    ///
+    /// let err = 61.check_range_named("minute", 0..60).unwrap_err()
+    ///              .with_path("[2]")
+    ///              .with_path("alarms")
+    ///              .with_path("clock");
+    ///
+    /// assert_eq!(err.path.as_ref().unwrap(), "clock.alarms[2].minute");
+    /// assert_eq!(err.to_string(), "clock.alarms[2].minute: value (61) above range (0..60)");
+    /// ```
+    pub fn with_path(mut self, field: &str) -> Self {
+        self.path = Some(match self.path {
+            Some(path)  => join_path(field, &path),
+            None        => field.to_owned(),
+        });
+        self
+    }
+
+    /// Returns which side of the allowed range the value lies on.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_check::{Check, Violation};
+    ///
+    /// assert_eq!(0.check_range(1..10).unwrap_err().violation(), Violation::BelowLower);
+    /// assert_eq!(10.check_range(1..10).unwrap_err().violation(), Violation::AboveUpper);
+    /// ```
+    pub fn violation(&self) -> Violation
+    where T: PartialOrd
+    {
+        let value = &self.outside_value;
+
+        if let ErrorKind::OutsideSet(ranges) = &self.kind {
+            if ranges.is_empty() {
+                return Violation::EmptySet;
+            }
+        }
+
+        if ! is_comparable(value, ref_bound(&self.allowed_range.lower))
+        || ! is_comparable(value, ref_bound(&self.allowed_range.upper)) {
+            Violation::Incomparable
+        }
+        else if ! lower_admits(&self.allowed_range.lower, value) {
+            Violation::BelowLower
+        }
+        else if ! upper_admits(&self.allowed_range.upper, value) {
+            Violation::AboveUpper
+        }
+        else {
+            Violation::InGap
+        }
+    }
+
     /// Converts this error to an error with the same values as another type.
     /// The other type must be `From`-convertible from this one.
     ///
@@ -85,6 +473,8 @@ impl<T> OutOfRangeError<T> {
         OutOfRangeError {
             allowed_range: self.allowed_range.convert(),
             outside_value: self.outside_value.into(),
+            kind: self.kind.convert(),
+            path: self.path,
         }
     }
 }
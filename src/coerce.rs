@@ -0,0 +1,383 @@
+use std::ops::{Bound, RangeBounds};
+
+use bounds::{Bounds, copy_bound};
+use check::{ErrorKind, OutOfRangeError};
+
+
+/// Trait that moves a value into a range instead of failing when it lies
+/// outside of it, for inputs that should be corrected rather than rejected.
+///
+/// This is implemented for the primitive integer types up to 64 bits wide,
+/// and for `f32` and `f64`. For integers, an excluded bound is treated as
+/// the integer next to it, so clamping to `0 .. 10` gives at most `9`. For
+/// floats, it is treated as the next representable float towards the range.
+pub trait Coerce<R: RangeBounds<Self>>: Sized {
+
+    /// Moves `self` to the nearest end of the given range if it lies outside
+    /// of it.
+    ///
+    /// Returns an error if the range is empty, as there is nowhere to move
+    /// the value to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_check::{Coerce, Adjustment};
+    ///
+    /// let volume = 120.clamp_to_range(0 ..= 100).unwrap();
+    /// assert_eq!(volume.value, 100);
+    /// assert_eq!(volume.adjustment, Adjustment::Clamped);
+    ///
+    /// assert_eq!((-3).clamp_to_range(0 .. 10).unwrap().value, 0);
+    /// assert_eq!(30.clamp_to_range(0 .. 10).unwrap().value, 9);
+    /// assert_eq!(5.clamp_to_range(0 .. 10).unwrap().adjustment, Adjustment::Unchanged);
+    /// ```
+    fn clamp_to_range(self, range: R) -> Result<Adjusted<Self>, OutOfRangeError<Self>>;
+
+    /// Wraps `self` around the given range if it lies outside of it, as
+    /// though the two ends of the range were joined together, like the hours
+    /// on a clock.
+    ///
+    /// Returns an error if the range is empty, or if either of its bounds is
+    /// unbounded, as there is then no distance to wrap around by.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_check::{Coerce, Adjustment};
+    ///
+    /// let hour = 26.wrap_to_range(0 .. 24).unwrap();
+    /// assert_eq!(hour.value, 2);
+    /// assert_eq!(hour.adjustment, Adjustment::Wrapped);
+    ///
+    /// assert_eq!((-90.0).wrap_to_range(0.0 .. 360.0).unwrap().value, 270.0);
+    /// assert!((-5).wrap_to_range(0 ..).is_err());
+    /// ```
+    fn wrap_to_range(self, range: R) -> Result<Adjusted<Self>, OutOfRangeError<Self>>;
+
+    /// Reflects `self` back into the given range if it lies outside of it,
+    /// as though it bounced off the end of the range it went past, for as
+    /// many times as it takes to land inside the range.
+    ///
+    /// If one of the bounds is unbounded, the value is reflected off the
+    /// other bound once, stopping at the highest or lowest value of the type
+    /// if it would go past it.
+    ///
+    /// Returns an error if the range is empty, or if it is a float range
+    /// that is too wide for the distance between its bounds to be finite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_check::{Coerce, Adjustment};
+    ///
+    /// let position = 12.reflect_into_range(0 ..= 10).unwrap();
+    /// assert_eq!(position.value, 8);
+    /// assert_eq!(position.adjustment, Adjustment::Reflected);
+    ///
+    /// assert_eq!((-3).reflect_into_range(0 ..= 10).unwrap().value, 3);
+    /// assert_eq!((-3).reflect_into_range(0 ..).unwrap().value, 3);
+    /// ```
+    fn reflect_into_range(self, range: R) -> Result<Adjusted<Self>, OutOfRangeError<Self>>;
+}
+
+impl<T, R> Coerce<R> for T
+where R: RangeBounds<T>,
+      T: Numeric,
+{
+    fn clamp_to_range(self, range: R) -> Result<Adjusted<Self>, OutOfRangeError<Self>> {
+        adjust(self, &range, Adjustment::Clamped, |value, lower, upper| {
+            match (lower, upper) {
+                (Some(lower), _) if value < lower  => Some(lower),
+                (_, Some(upper)) if value > upper  => Some(upper),
+                _                                  => None,
+            }
+        })
+    }
+
+    fn wrap_to_range(self, range: R) -> Result<Adjusted<Self>, OutOfRangeError<Self>> {
+        let bounds = bounds_of(&range);
+        adjust(self, &range, Adjustment::Wrapped, |value, _, _| value.wrap(&bounds))
+    }
+
+    fn reflect_into_range(self, range: R) -> Result<Adjusted<Self>, OutOfRangeError<Self>> {
+        let bounds = bounds_of(&range);
+        adjust(self, &range, Adjustment::Reflected, |value, _, _| value.reflect(&bounds))
+    }
+}
+
+/// Runs one of the adjustments, if the value needs adjusting. The function
+/// is passed the value along with the inclusive ends of the range, and
+/// returns `None` if the value cannot be adjusted.
+fn adjust<T, R, F>(value: T, range: &R, adjustment: Adjustment, f: F) -> Result<Adjusted<T>, OutOfRangeError<T>>
+where T: Numeric,
+      R: RangeBounds<T>,
+      F: FnOnce(T, Option<T>, Option<T>) -> Option<T>,
+{
+    if range.contains(&value) {
+        return Ok(Adjusted { value, original: value, adjustment: Adjustment::Unchanged });
+    }
+
+    let bounds = bounds_of(range);
+    let (lower, upper) = match inclusive_ends(&bounds) {
+        Some(ends)  => ends,
+        None        => {
+            let kind = if bounds.is_inverted() { ErrorKind::InvertedRange } else { ErrorKind::EmptyRange };
+            return Err(OutOfRangeError::new(bounds, value, kind));
+        }
+    };
+
+    // Values that cannot be compared, such as NaN, cannot be moved anywhere.
+    if value.partial_cmp(&value).is_none() {
+        return Err(OutOfRangeError::new(bounds, value, ErrorKind::Incomparable));
+    }
+
+    match f(value, lower, upper) {
+        Some(new_value) if range.contains(&new_value) => {
+            Ok(Adjusted { value: new_value, original: value, adjustment })
+        }
+        _ => {
+            Err(OutOfRangeError::new(bounds, value, ErrorKind::Outside))
+        }
+    }
+}
+
+fn bounds_of<T: Copy, R: RangeBounds<T>>(range: &R) -> Bounds<T> {
+    Bounds {
+        lower: copy_bound(range.start_bound()),
+        upper: copy_bound(range.end_bound()),
+    }
+}
+
+/// Turns the bounds into the lowest and highest values that lie within
+/// them, or returns `None` if there are no such values.
+fn inclusive_ends<T: Numeric>(bounds: &Bounds<T>) -> Option<(Option<T>, Option<T>)> {
+    let lower = match bounds.lower {
+        Bound::Included(n)  => Some(n),
+        Bound::Excluded(n)  => Some(n.successor()?),
+        Bound::Unbounded    => None,
+    };
+
+    let upper = match bounds.upper {
+        Bound::Included(n)  => Some(n),
+        Bound::Excluded(n)  => Some(n.predecessor()?),
+        Bound::Unbounded    => None,
+    };
+
+    match (lower, upper) {
+        (Some(l), Some(u)) if l > u  => None,
+        _                            => Some((lower, upper)),
+    }
+}
+
+
+/// A value that may have been moved into a range by one of the methods of
+/// the `Coerce` trait.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct Adjusted<T> {
+
+    /// The value, after it was moved into the range.
+    pub value: T,
+
+    /// The value before it was moved.
+    pub original: T,
+
+    /// How the value was moved, if it was.
+    pub adjustment: Adjustment,
+}
+
+impl<T> Adjusted<T> {
+
+    /// Returns whether the value had to be moved into the range.
+    pub fn was_adjusted(&self) -> bool {
+        self.adjustment != Adjustment::Unchanged
+    }
+}
+
+/// How a value was moved into a range.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum Adjustment {
+
+    /// The value was already within the range, so was left alone.
+    Unchanged,
+
+    /// The value was moved to the nearest end of the range.
+    Clamped,
+
+    /// The value was wrapped around the range.
+    Wrapped,
+
+    /// The value was reflected back into the range.
+    Reflected,
+}
+
+
+/// The number types that can be moved into ranges, for naming in the bounds
+/// of functions that call the methods of `Coerce`.
+///
+/// Values are moved off excluded bounds using their `Discrete` steps. The
+/// rest of the arithmetic is in a private trait that is only implemented for
+/// the primitive integer types up to 64 bits wide and the float types, so
+/// this trait cannot be implemented for any other type.
+///
+/// # Examples
+///
+/// ```
+/// use range_check::{Coerce, Numeric};
+///
+/// fn percentage<T: Numeric + From<u8>>(value: T) -> Option<T> {
+///     value.clamp_to_range(T::from(0) ..= T::from(100)).ok().map(|adjusted| adjusted.value)
+/// }
+///
+/// assert_eq!(percentage(120_u32), Some(100));
+/// assert_eq!(percentage(-2.5_f64), Some(0.0));
+/// assert_eq!(percentage(f64::NAN), None);
+/// ```
+pub trait Numeric: numeric::Arithmetic {}
+
+impl<T: numeric::Arithmetic> Numeric for T {}
+
+mod numeric {
+    use std::ops::Bound;
+    use bounds::Bounds;
+    use message::Discrete;
+
+    /// The arithmetic needed to move values into ranges.
+    pub trait Arithmetic: Discrete + PartialOrd + Copy {
+
+        /// Wraps this value around the bounds, which must not be empty.
+        fn wrap(self, bounds: &Bounds<Self>) -> Option<Self>;
+
+        /// Reflects this value off the bounds, which must not be empty.
+        fn reflect(self, bounds: &Bounds<Self>) -> Option<Self>;
+    }
+
+    // Integers are wrapped and reflected by doing the arithmetic in i128,
+    // which is wide enough that none of it can overflow.
+    macro_rules! impl_numeric_int {
+        ($($t:ty),*) => {
+            $(
+                impl Arithmetic for $t {
+                    fn wrap(self, bounds: &Bounds<Self>) -> Option<Self> {
+                        let lower = lowest(bounds)? as i128;
+                        let upper = highest(bounds)? as i128;
+                        let period = upper - lower + 1;
+                        let wrapped = lower + (self as i128 - lower).rem_euclid(period);
+                        Some(wrapped as $t)
+                    }
+
+                    fn reflect(self, bounds: &Bounds<Self>) -> Option<Self> {
+                        let value = self as i128;
+
+                        let reflected = match (lowest(bounds), highest(bounds)) {
+                            (Some(l), Some(u)) if l == u  => l as i128,
+                            (Some(l), Some(u)) => {
+                                let (l, span) = (l as i128, u as i128 - l as i128);
+                                let offset = (value - l).rem_euclid(2 * span);
+                                if offset <= span { l + offset } else { l + 2 * span - offset }
+                            }
+                            (Some(l), None)  => (2 * l as i128 - value).min(<$t>::MAX as i128),
+                            (None, Some(u))  => (2 * u as i128 - value).max(<$t>::MIN as i128),
+                            (None, None)     => value,
+                        };
+
+                        Some(reflected as $t)
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_numeric_int! { i8, i16, i32, i64, isize, u8, u16, u32, u64, usize }
+
+    // Floats are wrapped and reflected using the exact values of their
+    // bounds, so that wrapping into `0.0 .. 360.0` has a period of exactly
+    // 360. Any value that lands on an excluded bound is nudged inwards.
+    macro_rules! impl_numeric_float {
+        ($($t:ty),*) => {
+            $(
+                impl Arithmetic for $t {
+                    fn wrap(self, bounds: &Bounds<Self>) -> Option<Self> {
+                        let l = value_of(&bounds.lower)?;
+                        let u = value_of(&bounds.upper)?;
+
+                        let period = u - l;
+                        if ! self.is_finite() || ! period.is_finite() || period <= 0.0 {
+                            return None;
+                        }
+
+                        // The lower bound is the same point as the upper
+                        // bound, so use that if only the upper is included.
+                        let mut wrapped = l + (self - l).rem_euclid(period);
+                        if wrapped == l && ! bounds.contains(&l) {
+                            wrapped = u;
+                        }
+
+                        Some(nudge_inwards(wrapped, bounds))
+                    }
+
+                    fn reflect(self, bounds: &Bounds<Self>) -> Option<Self> {
+                        if ! self.is_finite() {
+                            return None;
+                        }
+
+                        let reflected = match (value_of(&bounds.lower), value_of(&bounds.upper)) {
+                            (Some(l), Some(u)) if l == u  => l,
+                            (Some(l), Some(u)) => {
+                                let span = u - l;
+                                if ! span.is_finite() {
+                                    return None;
+                                }
+
+                                let offset = (self - l).rem_euclid(2.0 * span);
+                                if offset <= span { l + offset } else { l + 2.0 * span - offset }
+                            }
+                            (Some(l), None)  => (l + (l - self)).min(<$t>::MAX),
+                            (None, Some(u))  => (u - (self - u)).max(<$t>::MIN),
+                            (None, None)     => self,
+                        };
+
+                        Some(nudge_inwards(reflected, bounds))
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_numeric_float! { f32, f64 }
+
+    /// The lowest value within the bounds, if they have a lower bound.
+    fn lowest<T: Arithmetic>(bounds: &Bounds<T>) -> Option<T> {
+        match bounds.lower {
+            Bound::Included(n)  => Some(n),
+            Bound::Excluded(n)  => n.successor(),
+            Bound::Unbounded    => None,
+        }
+    }
+
+    /// The highest value within the bounds, if they have an upper bound.
+    fn highest<T: Arithmetic>(bounds: &Bounds<T>) -> Option<T> {
+        match bounds.upper {
+            Bound::Included(n)  => Some(n),
+            Bound::Excluded(n)  => n.predecessor(),
+            Bound::Unbounded    => None,
+        }
+    }
+
+    fn value_of<T: Copy>(bound: &Bound<T>) -> Option<T> {
+        match *bound {
+            Bound::Included(n) | Bound::Excluded(n)  => Some(n),
+            Bound::Unbounded                         => None,
+        }
+    }
+
+    /// Moves a value that landed on or past an end of the bounds back inside
+    /// them, which can happen with excluded bounds and rounding.
+    fn nudge_inwards<T: Arithmetic>(value: T, bounds: &Bounds<T>) -> T {
+        match (lowest(bounds), highest(bounds)) {
+            (Some(l), _) if value < l  => l,
+            (_, Some(u)) if value > u  => u,
+            _                          => value,
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use std::error::Error as ErrorTrait;
+use std::fmt;
+
+use any::AnyOutOfRangeError;
+use check::OutOfRangeError;
+
+
+/// Trait for error types that can be created from a failed range check, so
+/// that `Check::check_range_as` can return them directly.
+///
+/// The `OutOfRangeError` holds everything about the check that failed: the
+/// value, the range, why the check failed, and, using its `violation`
+/// method, which side of the range the value was on.
+///
+/// # Examples
+///
+/// ```
+/// use range_check::{Check, OutOfRangeError, RangeError, Violation};
+///
+/// #[derive(PartialEq, Debug)]
+/// enum ConfigError {
+///     TooSmall(i64),
+///     TooLarge(i64),
+///     Invalid,
+/// }
+///
+/// impl RangeError<i64> for ConfigError {
+///     fn from_range_error(error: OutOfRangeError<i64>) -> Self {
+///         match error.violation() {
+///             Violation::BelowLower  => ConfigError::TooSmall(error.outside_value),
+///             Violation::AboveUpper  => ConfigError::TooLarge(error.outside_value),
+///             _                      => ConfigError::Invalid,
+///         }
+///     }
+/// }
+///
+/// fn check_threads(threads: i64) -> Result<i64, ConfigError> {
+///     threads.check_range_as(1 ..= 64)
+/// }
+///
+/// assert_eq!(check_threads(8), Ok(8));
+/// assert_eq!(check_threads(0), Err(ConfigError::TooSmall(0)));
+/// assert_eq!(check_threads(128), Err(ConfigError::TooLarge(128)));
+/// ```
+pub trait RangeError<T> {
+
+    /// Creates this error from the details of a failed range check.
+    fn from_range_error(error: OutOfRangeError<T>) -> Self;
+}
+
+impl<T> RangeError<T> for OutOfRangeError<T> {
+    fn from_range_error(error: OutOfRangeError<T>) -> Self {
+        error
+    }
+}
+
+impl<T: fmt::Debug + PartialOrd + 'static> RangeError<T> for AnyOutOfRangeError {
+    fn from_range_error(error: OutOfRangeError<T>) -> Self {
+        AnyOutOfRangeError::from(error)
+    }
+}
+
+impl<T: fmt::Debug + PartialOrd + Send + Sync + 'static> RangeError<T> for Box<dyn ErrorTrait + Send + Sync> {
+    fn from_range_error(error: OutOfRangeError<T>) -> Self {
+        Box::new(error)
+    }
+}
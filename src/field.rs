@@ -0,0 +1,78 @@
+use std::error::Error as ErrorTrait;
+use std::fmt;
+
+use check::OutOfRangeError;
+
+
+/// The error returned when one of the fields of a struct is out of range,
+/// holding the range check that failed with the name of the field as its
+/// path.
+///
+/// This is the error type of the `validate` method and checked constructor
+/// that `#[derive(RangeCheck)]` from the `range_check_derive` crate generates.
+///
+/// # Examples
+///
+/// ```
+/// use range_check::{Check, FieldError};
+///
+/// let minute: i8 = 60;
+/// let err = minute.check_range(0..60).map_err(|e| FieldError::new("minute", e)).unwrap_err();
+///
+/// assert_eq!(err.field(), "minute");
+/// assert_eq!(err.error.path.as_ref().unwrap(), "minute");
+/// assert_eq!(err.to_string(), "minute: value (60) above range (0..60)");
+/// ```
+#[derive(PartialEq, Debug, Clone)]
+pub struct FieldError<T> {
+
+    /// The range check that failed, with the field’s name at the start of
+    /// its path.
+    pub error: OutOfRangeError<T>,
+}
+
+impl<T> FieldError<T> {
+
+    /// Creates a new error for the field with the given name, adding the
+    /// name to the start of the error’s path.
+    pub fn new(field: &str, error: OutOfRangeError<T>) -> Self {
+        FieldError { error: error.with_path(field) }
+    }
+
+    /// Returns the name of the field that was out of range. Fields of tuple
+    /// structs are named by their index. When checking nested values with a
+    /// `Validator`, this is the path to the field, such as `clock.minute`.
+    pub fn field(&self) -> &str {
+        self.error.path.as_deref().unwrap_or_default()
+    }
+}
+
+impl<T: fmt::Debug + PartialOrd> fmt::Display for FieldError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.error.fmt(f)
+    }
+}
+
+impl<T: fmt::Debug + PartialOrd + 'static> ErrorTrait for FieldError<T> {
+    fn source(&self) -> Option<&(dyn ErrorTrait + 'static)> {
+        Some(&self.error)
+    }
+}
+
+
+/// Joins a field name onto a path, without a dot if the field is an index.
+/// An empty prefix or field is skipped, rather than leaving a stray dot.
+pub(crate) fn join_path(prefix: &str, field: &str) -> String {
+    if prefix.is_empty() {
+        field.to_owned()
+    }
+    else if field.is_empty() {
+        prefix.to_owned()
+    }
+    else if field.starts_with('[') {
+        format!("{}{}", prefix, field)
+    }
+    else {
+        format!("{}.{}", prefix, field)
+    }
+}
@@ -0,0 +1,178 @@
+use std::fmt;
+use std::ops::Bound;
+
+use bounds::Bounds;
+use check::OutOfRangeError;
+use interval::IntervalNotation;
+
+
+/// The ways a range can be written, both on its own and inside the message
+/// of an `OutOfRangeError`.
+///
+/// Each style can express every range, including ones with an excluded
+/// lower bound, which has no Rust syntax of its own.
+///
+/// # Examples
+///
+/// ```
+/// use range_check::{Bounds, BoundsFormat, IntervalNotation};
+///
+/// let range = Bounds::open(5, 10);
+/// assert_eq!(range.display(&BoundsFormat::Rust).to_string(), "5<..10");
+/// assert_eq!(range.display(&BoundsFormat::Interval(IntervalNotation::new())).to_string(), "(5, 10)");
+/// assert_eq!(range.display(&BoundsFormat::Inequality).to_string(), "5 < x < 10");
+/// assert_eq!(range.display(&BoundsFormat::English).to_string(), "greater than 5 and less than 10");
+/// ```
+#[derive(PartialEq, Debug, Clone, Default)]
+pub enum BoundsFormat {
+
+    /// Rust’s range syntax, such as `1..5` or `1..=5`, which is what
+    /// `Bounds` uses for its `Display` implementation. An excluded lower
+    /// bound is followed by `<`, such as `5<..10`.
+    #[default]
+    Rust,
+
+    /// Mathematical interval notation, such as `[1, 5)` or `(5, ∞)`.
+    Interval(IntervalNotation),
+
+    /// A pair of inequalities around `x`, such as `5 < x <= 10`.
+    Inequality,
+
+    /// A description in plain English, such as `at least 1 and less than 5`.
+    English,
+}
+
+impl BoundsFormat {
+
+    /// Writes the given range in this format.
+    pub(crate) fn write<T: fmt::Debug>(&self, f: &mut fmt::Formatter, bounds: &Bounds<T>) -> fmt::Result {
+        match self {
+            BoundsFormat::Rust                => write_rust(f, bounds),
+            BoundsFormat::Interval(notation)  => notation.write(f, bounds),
+            BoundsFormat::Inequality          => write_inequality(f, bounds),
+            BoundsFormat::English             => write_english(f, bounds),
+        }
+    }
+}
+
+fn write_rust<T: fmt::Debug>(f: &mut fmt::Formatter, bounds: &Bounds<T>) -> fmt::Result {
+    match &bounds.lower {
+        Bound::Included(n)  => write!(f, "{:?}", n)?,
+        Bound::Excluded(n)  => write!(f, "{:?}<", n)?,
+        Bound::Unbounded    => {},
+    }
+
+    write!(f, "..")?;
+
+    match &bounds.upper {
+        Bound::Included(n)  => write!(f, "={:?}", n),
+        Bound::Excluded(n)  => write!(f, "{:?}", n),
+        Bound::Unbounded    => Ok(()),
+    }
+}
+
+fn write_inequality<T: fmt::Debug>(f: &mut fmt::Formatter, bounds: &Bounds<T>) -> fmt::Result {
+    match (&bounds.lower, &bounds.upper) {
+        (Bound::Unbounded, Bound::Unbounded) => return write!(f, "-∞ < x < ∞"),
+        (Bound::Unbounded, _)                => write!(f, "x")?,
+        (Bound::Included(n), _)              => write!(f, "{:?} <= x", n)?,
+        (Bound::Excluded(n), _)              => write!(f, "{:?} < x", n)?,
+    }
+
+    match &bounds.upper {
+        Bound::Included(n)  => write!(f, " <= {:?}", n),
+        Bound::Excluded(n)  => write!(f, " < {:?}", n),
+        Bound::Unbounded    => Ok(()),
+    }
+}
+
+fn write_english<T: fmt::Debug>(f: &mut fmt::Formatter, bounds: &Bounds<T>) -> fmt::Result {
+    let lower_bounded = ! matches!(bounds.lower, Bound::Unbounded);
+    let upper_bounded = ! matches!(bounds.upper, Bound::Unbounded);
+
+    if ! lower_bounded && ! upper_bounded {
+        return write!(f, "any value");
+    }
+
+    match &bounds.lower {
+        Bound::Included(n)  => write!(f, "at least {:?}", n)?,
+        Bound::Excluded(n)  => write!(f, "greater than {:?}", n)?,
+        Bound::Unbounded    => {},
+    }
+
+    if lower_bounded && upper_bounded {
+        write!(f, " and ")?;
+    }
+
+    match &bounds.upper {
+        Bound::Included(n)  => write!(f, "at most {:?}", n),
+        Bound::Excluded(n)  => write!(f, "less than {:?}", n),
+        Bound::Unbounded    => Ok(()),
+    }
+}
+
+
+impl<T> Bounds<T> {
+
+    /// Returns a value that displays this range in the given format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_check::{Bounds, BoundsFormat};
+    ///
+    /// assert_eq!(Bounds::half_open(0, 24).display(&BoundsFormat::Inequality).to_string(),
+    ///            "0 <= x < 24");
+    /// ```
+    pub fn display<'a>(&'a self, format: &'a BoundsFormat) -> DisplayBounds<'a, T> {
+        DisplayBounds { bounds: self, format }
+    }
+}
+
+/// A range displayed in a particular format, returned by `Bounds::display`.
+#[derive(Debug)]
+pub struct DisplayBounds<'a, T> {
+    bounds: &'a Bounds<T>,
+    format: &'a BoundsFormat,
+}
+
+impl<T: fmt::Debug> fmt::Display for DisplayBounds<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.format.write(f, self.bounds)
+    }
+}
+
+
+impl<T> OutOfRangeError<T> {
+
+    /// Returns a value that displays this error’s message with its range in
+    /// the given format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_check::{BoundsFormat, Check};
+    ///
+    /// let err = 24.check_range(0..24).unwrap_err();
+    /// assert_eq!(err.display(&BoundsFormat::English).to_string(),
+    ///            "value (24) above range (at least 0 and less than 24)");
+    /// ```
+    pub fn display<'a>(&'a self, format: &'a BoundsFormat) -> DisplayError<'a, T> {
+        DisplayError { error: self, format }
+    }
+}
+
+/// An error displayed with its range in a particular format, returned by
+/// `OutOfRangeError::display`.
+#[derive(Debug)]
+pub struct DisplayError<'a, T> {
+    error: &'a OutOfRangeError<T>,
+    format: &'a BoundsFormat,
+}
+
+impl<T: fmt::Debug + PartialOrd> fmt::Display for DisplayError<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.error.write_message(f, &|f, range| self.format.write(f, range))
+    }
+}
+
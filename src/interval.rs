@@ -0,0 +1,239 @@
+use std::borrow::Cow;
+use std::fmt;
+use std::ops::Bound;
+use std::str::FromStr;
+
+use bounds::Bounds;
+use parse::{ParseBoundsError, ParseErrorKind};
+
+
+/// The settings for writing and reading ranges in the mathematical interval
+/// notation of ISO 31-11, such as `[0, 24)` or `(0, ∞)`, instead of Rust’s
+/// range syntax.
+///
+/// A square bracket next to a value means it is included in the range, and
+/// a parenthesis means it is excluded. Unbounded ends are written as
+/// infinity. Alternatively, excluded values can use outward-facing square
+/// brackets, such as `]1; 5]`, which is common in continental Europe.
+///
+/// # Examples
+///
+/// ```
+/// use range_check::{Bounds, BoundsFormat, IntervalNotation};
+///
+/// let notation = BoundsFormat::Interval(IntervalNotation::new());
+/// assert_eq!(Bounds::half_open(0, 24).display(&notation).to_string(), "[0, 24)");
+/// assert_eq!(Bounds::at_least(0).display(&notation).to_string(), "[0, ∞)");
+///
+/// let european = BoundsFormat::Interval(IntervalNotation::new().reversed_brackets().separator("; "));
+/// assert_eq!(Bounds::open(1, 5).display(&european).to_string(), "]1; 5[");
+/// ```
+///
+/// The separator and infinity can be given as owned strings, so they can
+/// come from configuration that is only known at runtime:
+///
+/// ```
+/// use range_check::{Bounds, BoundsFormat, IntervalNotation};
+///
+/// let infinity = String::from("unendlich");
+/// let notation = BoundsFormat::Interval(IntervalNotation::new().infinity(infinity));
+/// assert_eq!(Bounds::at_least(0).display(&notation).to_string(), "[0, unendlich)");
+/// ```
+#[derive(PartialEq, Debug, Clone)]
+pub struct IntervalNotation {
+    separator: Cow<'static, str>,
+    reversed: bool,
+    infinity: Cow<'static, str>,
+}
+
+impl IntervalNotation {
+
+    /// Creates the default notation, which uses parentheses for excluded
+    /// values, `, ` between the values, and `∞` for unbounded ends.
+    pub fn new() -> Self {
+        IntervalNotation { separator: Cow::Borrowed(", "), reversed: false, infinity: Cow::Borrowed("∞") }
+    }
+
+    /// Uses the given text between the two values. When parsing, the
+    /// whitespace around it is optional.
+    pub fn separator<S: Into<Cow<'static, str>>>(self, separator: S) -> Self {
+        IntervalNotation { separator: separator.into(), ..self }
+    }
+
+    /// Uses outward-facing square brackets for excluded values, such as
+    /// `]1, 5[`, instead of parentheses.
+    pub fn reversed_brackets(self) -> Self {
+        IntervalNotation { reversed: true, ..self }
+    }
+
+    /// Uses the given text for infinity, such as `inf`, instead of `∞`.
+    pub fn infinity<S: Into<Cow<'static, str>>>(self, infinity: S) -> Self {
+        IntervalNotation { infinity: infinity.into(), ..self }
+    }
+
+    /// Writes the given range in this notation.
+    pub(crate) fn write<T: fmt::Debug>(&self, f: &mut fmt::Formatter, bounds: &Bounds<T>) -> fmt::Result {
+        let (open_lower, open_upper) = if self.reversed { (']', '[') } else { ('(', ')') };
+
+        match &bounds.lower {
+            Bound::Included(n)  => write!(f, "[{:?}", n)?,
+            Bound::Excluded(n)  => write!(f, "{}{:?}", open_lower, n)?,
+            Bound::Unbounded    => write!(f, "{}-{}", open_lower, self.infinity)?,
+        }
+
+        write!(f, "{}", self.separator)?;
+
+        match &bounds.upper {
+            Bound::Included(n)  => write!(f, "{:?}]", n),
+            Bound::Excluded(n)  => write!(f, "{:?}{}", n, open_upper),
+            Bound::Unbounded    => write!(f, "{}{}", self.infinity, open_upper),
+        }
+    }
+
+    /// Parses a range written in this notation.
+    ///
+    /// Either bracket style is accepted for excluded values, whichever one
+    /// this notation writes. As well as this notation’s own infinity, `∞`
+    /// and `inf` are accepted for unbounded ends, optionally signed, and
+    /// whitespace is allowed around each value. An unbounded end must be
+    /// next to a bracket that excludes its value, as a bracket that includes
+    /// it means the value itself is in the range, as with a float infinity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_check::{Bounds, IntervalNotation};
+    /// use std::ops::Bound;
+    ///
+    /// let notation = IntervalNotation::new();
+    /// assert_eq!(notation.parse("[0, 24)"), Ok(Bounds::half_open(0, 24)));
+    /// assert_eq!(notation.parse("(-inf, 5]"), Ok(Bounds::at_most(5)));
+    ///
+    /// let european = IntervalNotation::new().separator(";");
+    /// assert_eq!(european.parse("]1;5]"), Ok(Bounds { lower: Bound::Excluded(1), upper: Bound::Included(5) }));
+    ///
+    /// let err = notation.parse::<u8>("[0; 24)").unwrap_err();
+    /// assert_eq!(err.to_string(), "expected separator at byte 1");
+    /// ```
+    pub fn parse<T: FromStr>(&self, input: &str) -> Result<Bounds<T>, ParseBoundsError<T::Err>> {
+        let start = input.len() - input.trim_start().len();
+        let trimmed = input.trim();
+
+        let lower_included = match trimmed.chars().next() {
+            Some('[')        => true,
+            Some('(' | ']')  => false,
+            _                => return Err(error(start, ParseErrorKind::MissingBracket)),
+        };
+
+        let end = start + trimmed.len();
+        let upper_included = match trimmed[1 ..].chars().next_back() {
+            Some(']')        => true,
+            Some(')' | '[')  => false,
+            Some(c)          => return Err(error(end - c.len_utf8(), ParseErrorKind::MissingBracket)),
+            None             => return Err(error(end, ParseErrorKind::MissingBracket)),
+        };
+
+        let inner_start = start + 1;
+        let inner = &input[inner_start .. end - 1];
+
+        let (split, separator_len) = match self.find_separator(inner) {
+            Some(found)  => found,
+            None         => return Err(error(inner_start, ParseErrorKind::MissingSeparator)),
+        };
+
+        let upper_start = inner_start + split + separator_len;
+
+        let lower = match self.parse_value(&inner[.. split], inner_start, &[ "-", "−" ], lower_included)? {
+            None                            => Bound::Unbounded,
+            Some(value) if lower_included   => Bound::Included(value),
+            Some(value)                     => Bound::Excluded(value),
+        };
+
+        let upper = match self.parse_value(&inner[split + separator_len ..], upper_start, &[ "+", "" ], upper_included)? {
+            None                            => Bound::Unbounded,
+            Some(value) if upper_included   => Bound::Included(value),
+            Some(value)                     => Bound::Excluded(value),
+        };
+
+        Ok(Bounds { lower, upper })
+    }
+
+    /// Finds the separator between the two values, returning its position
+    /// and length. The separator is searched for as it was given first, and
+    /// then without the whitespace around it. A match is skipped if there is
+    /// no value before it yet, so that a separator such as `-` is not found
+    /// in the sign of a negative lower bound.
+    fn find_separator(&self, inner: &str) -> Option<(usize, usize)> {
+        let separators = match self.separator.trim() {
+            ""         => vec![ &self.separator[..] ],
+            trimmed    => vec![ &self.separator[..], trimmed ],
+        };
+
+        let mut matches = separators.into_iter()
+            .flat_map(|separator| inner.match_indices(separator).map(move |(i, _)| (i, separator.len())));
+
+        let first = matches.clone().next();
+        matches.find(|&(i, _)| has_value(&inner[.. i])).or(first)
+    }
+
+    /// Parses the value in the given piece of text, which begins at the
+    /// given offset in the input, returning `None` if it is infinity
+    /// preceded by one of the given signs.
+    ///
+    /// Infinity only means an unbounded end next to a bracket that excludes
+    /// its value. Next to one that includes it, the text is parsed as a value
+    /// instead, so float ranges that include infinity keep their bounds.
+    /// Next to an excluding bracket, this notation’s own infinity is always
+    /// unbounded, but any other text that parses as a value is kept as one.
+    fn parse_value<T: FromStr>(&self, text: &str, offset: usize, signs: &[&str], included: bool) -> Result<Option<T>, ParseBoundsError<T::Err>> {
+        let trimmed = text.trim_start();
+        let offset = offset + (text.len() - trimmed.len());
+        let trimmed = trimmed.trim_end();
+
+        if trimmed.is_empty() {
+            return Err(error(offset, ParseErrorKind::MissingValue));
+        }
+
+        let mut unsigned = signs.iter().filter_map(|sign| trimmed.strip_prefix(sign));
+
+        if ! included && unsigned.clone().any(|rest| rest == self.infinity) {
+            return Ok(None);
+        }
+
+        match trimmed.parse() {
+            Ok(value)                                    => Ok(Some(value)),
+            Err(_) if ! included && unsigned.any(|rest| self.is_infinity(rest))  => Ok(None),
+            Err(e)                                       => Err(error(offset, ParseErrorKind::InvalidValue(e))),
+        }
+    }
+
+    /// Whether the given text is an unsigned infinity.
+    fn is_infinity(&self, text: &str) -> bool {
+        text == "∞" || text == self.infinity || text.eq_ignore_ascii_case("inf")
+    }
+}
+
+impl Default for IntervalNotation {
+    fn default() -> Self {
+        IntervalNotation::new()
+    }
+}
+
+/// Whether the given text before a separator holds a value, rather than
+/// being empty or only a sign.
+fn has_value(text: &str) -> bool {
+    ! matches!(text.trim(), "" | "-" | "+" | "−")
+}
+
+fn error<E>(offset: usize, kind: ParseErrorKind<E>) -> ParseBoundsError<E> {
+    ParseBoundsError { offset, kind }
+}
+
+
+impl<T: FromStr> Bounds<T> {
+
+    /// Parses a range written in the given interval notation.
+    pub fn parse_interval(input: &str, notation: &IntervalNotation) -> Result<Self, ParseBoundsError<T::Err>> {
+        notation.parse(input)
+    }
+}
@@ -0,0 +1,121 @@
+use std::cmp::Ordering;
+use std::ops::{Bound, RangeBounds};
+
+use bounds::Bounds;
+use check::{ErrorKind, OutOfRangeError};
+
+
+/// Trait for range-checking a value by something other than its own
+/// `PartialOrd` implementation: either by a key extracted from it, or with
+/// a custom comparison function.
+///
+/// Either way, the value itself is returned untouched if it passes.
+pub trait CheckBy: Sized {
+
+    /// Checks whether the key extracted from `self` is within the given
+    /// range. If it is, re-returns `self`. Otherwise, returns an `Error` that
+    /// contains both the key and the range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_check::CheckBy;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct Alarm { label: String, hour: u8 }
+    ///
+    /// let alarm = Alarm { label: String::from("Wake up"), hour: 7 };
+    /// let alarm = alarm.check_range_by_key(|a| a.hour, 0..24).unwrap();
+    /// assert_eq!(alarm.label, "Wake up");
+    ///
+    /// let alarm = Alarm { label: String::from("Never"), hour: 25 };
+    /// assert_eq!(alarm.check_range_by_key(|a| a.hour, 0..24).unwrap_err().to_string(),
+    ///            "value (25) above range (0..24)");
+    /// ```
+    fn check_range_by_key<K, F, R>(self, key: F, range: R) -> Result<Self, OutOfRangeError<K>>
+    where F: FnOnce(&Self) -> K,
+          K: PartialOrd + Clone,
+          R: RangeBounds<K>;
+
+    /// Checks whether `self` is within the given range, comparing it with
+    /// the range’s bounds using the given function. If it is, re-returns
+    /// `self`. Otherwise, returns an `Error` that contains both the value and
+    /// the range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_check::CheckBy;
+    ///
+    /// fn case_insensitive(a: &&str, b: &&str) -> std::cmp::Ordering {
+    ///     a.to_lowercase().cmp(&b.to_lowercase())
+    /// }
+    ///
+    /// assert_eq!("Marmoset".check_range_by(case_insensitive, "a" .. "n"), Ok("Marmoset"));
+    /// assert!("marmoset".check_range_by(case_insensitive, "A" .. "N").is_ok());
+    /// assert!("Tamarin".check_range_by(case_insensitive, "a" .. "n").is_err());
+    /// ```
+    fn check_range_by<F, R>(self, compare: F, range: R) -> Result<Self, OutOfRangeError<Self>>
+    where F: FnMut(&Self, &Self) -> Ordering,
+          Self: Clone,
+          R: RangeBounds<Self>;
+}
+
+impl<T> CheckBy for T {
+    fn check_range_by_key<K, F, R>(self, key: F, range: R) -> Result<Self, OutOfRangeError<K>>
+    where F: FnOnce(&Self) -> K,
+          K: PartialOrd + Clone,
+          R: RangeBounds<K>,
+    {
+        let key = key(&self);
+
+        if range.contains(&key) {
+            Ok(self)
+        }
+        else {
+            let bounds = Bounds::from_range_bounds(&range);
+            let kind = ErrorKind::of_failed_check(&bounds, &key);
+            Err(OutOfRangeError::new(bounds, key, kind))
+        }
+    }
+
+    fn check_range_by<F, R>(self, mut compare: F, range: R) -> Result<Self, OutOfRangeError<Self>>
+    where F: FnMut(&Self, &Self) -> Ordering,
+          Self: Clone,
+          R: RangeBounds<Self>,
+    {
+        let above_lower = match range.start_bound() {
+            Bound::Unbounded    => true,
+            Bound::Included(l)  => compare(&self, l) != Ordering::Less,
+            Bound::Excluded(l)  => compare(&self, l) == Ordering::Greater,
+        };
+
+        let below_upper = match range.end_bound() {
+            Bound::Unbounded    => true,
+            Bound::Included(u)  => compare(&self, u) != Ordering::Greater,
+            Bound::Excluded(u)  => compare(&self, u) == Ordering::Less,
+        };
+
+        if above_lower && below_upper {
+            return Ok(self);
+        }
+
+        let bounds = Bounds::from_range_bounds(&range);
+        let kind = match (&bounds.lower, &bounds.upper) {
+            (Bound::Included(l), Bound::Included(u)) => match compare(l, u) {
+                Ordering::Greater  => ErrorKind::InvertedRange,
+                _                  => ErrorKind::Outside,
+            },
+            (Bound::Included(l), Bound::Excluded(u)) |
+            (Bound::Excluded(l), Bound::Included(u)) |
+            (Bound::Excluded(l), Bound::Excluded(u)) => match compare(l, u) {
+                Ordering::Greater  => ErrorKind::InvertedRange,
+                Ordering::Equal    => ErrorKind::EmptyRange,
+                Ordering::Less     => ErrorKind::Outside,
+            },
+            _ => ErrorKind::Outside,
+        };
+
+        Err(OutOfRangeError::new(bounds, self, kind))
+    }
+}
@@ -41,7 +41,7 @@
 //! use range_check::Check;
 //!
 //! assert_eq!(24680.check_range(1..9999).unwrap_err().to_string(),
-//!            "value (24680) outside of range (1..9999)");
+//!            "value (24680) above range (1..9999)");
 //! ```
 //!
 //! Failing early if a value is outside a range
@@ -104,6 +104,156 @@
 //! assert!(Clock::new(49, 23456).is_err());
 //! assert!(Clock::new(61, 0).is_err());
 //! ```
+//!
+//! When there is no `From` conversion between the types, such as between
+//! floats and characters, convert the error into an
+//! [`AnyOutOfRangeError`](struct.AnyOutOfRangeError.html) instead, which
+//! erases the type of the value. The `?` operator does this for you:
+//!
+//! ```
+//! use range_check::{AnyOutOfRangeError, Check};
+//!
+//! fn check_key(key: char, velocity: f32) -> Result<(), AnyOutOfRangeError> {
+//!     key.check_range('A' ..= 'G')?;
+//!     velocity.check_range(0.0 ..= 1.0)?;
+//!     Ok(())
+//! }
+//!
+//! assert!(check_key('C', 0.8).is_ok());
+//! assert!(check_key('H', 0.8).is_err());
+//! ```
+//!
+//!
+//! Reporting every value outside a range
+//! -------------------------------------
+//!
+//! Returning early means only the first bad value gets reported. To check
+//! every value and report all the ones that failed, use a
+//! [`Validator`](struct.Validator.html), which records the name of each
+//! field that was out of range:
+//!
+//! ```
+//! use range_check::{ValidationReport, Validator};
+//!
+//! struct Clock {
+//!     hour: i8,
+//!     minute: i8,
+//! }
+//!
+//! impl Clock {
+//!     fn new(hour: i8, minute: i8) -> Result<Clock, ValidationReport<i8>> {
+//!         let mut validator = Validator::new();
+//!         validator.check("hour", hour, 0..24)
+//!                  .check("minute", minute, 0..60);
+//!
+//!         validator.finish()?;
+//!         Ok(Clock { hour, minute })
+//!     }
+//! }
+//!
+//! assert!(Clock::new(23, 59).is_ok());
+//! assert_eq!(Clock::new(24, 60).err().unwrap().len(), 2);
+//! ```
+//!
+//!
+//! Storing ranges
+//! --------------
+//!
+//! Every `OutOfRangeError` holds the range it was checked against as a
+//! [`Bounds`](struct.Bounds.html) value. You can also create a `Bounds`
+//! yourself, store it, and pass it to `check_range` later on, as it
+//! implements `RangeBounds` just like the standard `Range` types:
+//!
+//! ```
+//! use range_check::{Bounds, Check};
+//!
+//! let minutes = Bounds::half_open(0, 60);
+//!
+//! assert!(59.check_range(&minutes).is_ok());
+//! assert!(60.check_range(&minutes).is_err());
+//!
+//! let err = 60.check_range(minutes).unwrap_err();
+//! assert!(30.check_range(err.allowed_range).is_ok());
+//! ```
+//!
+//!
+//! Checking against several ranges
+//! -------------------------------
+//!
+//! When a value is allowed to be in one of several ranges, collect them into
+//! a [`RangeSet`](struct.RangeSet.html) and check against that instead. The
+//! error lists every range in the set:
+//!
+//! ```
+//! use range_check::{Bounds, Check, RangeSet};
+//!
+//! let ports: RangeSet<u16> = vec![
+//!     Bounds::half_open(1, 1024),
+//!     Bounds::half_open(8000, 9000),
+//! ].into_iter().collect();
+//!
+//! assert_eq!(8080.check_range(&ports), Ok(8080));
+//! assert_eq!(5000.check_range(&ports).unwrap_err().to_string(),
+//!            "value (5000) between ranges (1..1024, 8000..9000)");
+//! ```
+//!
+//!
+//! Messages for end users
+//! ----------------------
+//!
+//! The `Display` form of an `OutOfRangeError` is written for programmers.
+//! To tell end users what they should have entered instead, use its
+//! [`message`](struct.OutOfRangeError.html#method.message) method, or
+//! `message_in` with a [`Catalog`](trait.Catalog.html) for another language:
+//!
+//! ```
+//! use range_check::{Check, GermanCatalog};
+//!
+//! let err = 24680.check_range(1..9999).unwrap_err();
+//! assert_eq!(err.message().inclusive().to_string(), "must be between 1 and 9998");
+//! assert_eq!(err.message_in(&GermanCatalog).inclusive().to_string(), "muss zwischen 1 und 9998 liegen");
+//! ```
+//!
+//!
+//! Serialising ranges and errors
+//! -----------------------------
+//!
+//! With the `serde` feature enabled, `Bounds` and `OutOfRangeError` implement
+//! Serde’s `Serialize` and `Deserialize` traits. A `Bounds` is serialised as
+//! a structure with a `lower` and an `upper` field, each of which is either
+//! null, for an unbounded end, or holds the value along with whether it is
+//! included in the range:
+//!
+//! ```json
+//! { "lower": { "value": 0, "inclusive": true },
+//!   "upper": { "value": 24, "inclusive": false } }
+//! ```
+//!
+//! An `OutOfRangeError` is serialised with its range in this form, so the
+//! exact range that was violated can be reconstructed on the other side. To
+//! serialise a `Bounds` as a string such as `"0..24"` instead, use the
+//! `bounds_as_string` module with Serde’s `with` attribute.
+//!
+//!
+//! Asserting that a value is in range
+//! ----------------------------------
+//!
+//! For invariants that should never fail, the
+//! [`assert_in_range!`](macro.assert_in_range.html) macro panics with the
+//! range and the value, reported at the line of the assertion. Its
+//! [`debug_assert_in_range!`](macro.debug_assert_in_range.html) counterpart
+//! is only checked in debug builds:
+//!
+//! ```
+//! #[macro_use] extern crate range_check;
+//!
+//! # fn main() {
+//! let buffer = [0_u8; 16];
+//! for index in 0 .. 16 {
+//!     debug_assert_in_range!(index, 0 .. buffer.len());
+//! }
+//! # }
+//! ```
 
 
 #![crate_name = "range_check"]
@@ -117,7 +267,79 @@
 #![warn(unused_qualifications)]
 #![warn(unused_results)]
 
+#[cfg(feature = "serde")]
+extern crate serde;
+
 mod check;
-pub use check::{Check, OutOfRangeError};
+pub use check::{Check, OutOfRangeError, ErrorKind, Violation, Position};
+
+mod any;
+pub use any::AnyOutOfRangeError;
+
+mod borrowed;
+pub use borrowed::CheckRef;
+
+mod assert;
+#[doc(hidden)]
+pub use assert::assert_failed as __assert_in_range_failed;
 
 mod bounds;
+pub use bounds::Bounds;
+
+mod parse;
+pub use parse::{ParseBoundsError, ParseErrorKind};
+
+mod interval;
+pub use interval::IntervalNotation;
+
+mod format;
+pub use format::{BoundsFormat, DisplayBounds, DisplayError};
+
+mod message;
+pub use message::{Catalog, EnglishCatalog, GermanCatalog, Discrete, Message};
+
+#[cfg(feature = "serde")]
+mod serialise;
+#[cfg(feature = "serde")]
+pub use serialise::bounds_as_string;
+
+mod custom;
+pub use custom::RangeError;
+
+mod field;
+pub use field::FieldError;
+
+mod validate;
+pub use validate::{Validator, ValidationReport};
+
+mod algebra;
+
+mod set;
+pub use set::RangeSet;
+
+mod map;
+pub use map::{IntervalMap, OverlapError};
+
+mod bounded;
+pub use bounded::Bounded;
+
+mod keyed;
+pub use keyed::CheckBy;
+
+mod mixed;
+pub use mixed::{CheckMixed, ExactCmp, MixedOutOfRangeError};
+
+mod narrow;
+pub use narrow::{CheckInto, RangeOf};
+
+mod coerce;
+pub use coerce::{Coerce, Adjusted, Adjustment, Numeric};
+
+mod total;
+pub use total::TotalOrder;
+
+mod tree;
+pub use tree::IntervalTree;
+
+mod target;
+pub use target::RangeTarget;
@@ -0,0 +1,166 @@
+use std::error::Error as ErrorTrait;
+use std::fmt;
+
+use algebra::{cmp_lowers, lower_admits};
+use bounds::Bounds;
+use check::OutOfRangeError;
+use set::RangeSet;
+
+
+/// A map from ranges to values, for sorting values into buckets.
+///
+/// No two ranges in the map may overlap, so every value lies within at most
+/// one range. The ranges are kept sorted, so looking up the range for a value
+/// takes logarithmic time.
+///
+/// # Examples
+///
+/// ```
+/// use range_check::{Bounds, IntervalMap};
+///
+/// let mut grades = IntervalMap::new();
+/// grades.insert(Bounds::half_open(0, 50), "fail").unwrap();
+/// grades.insert(Bounds::half_open(50, 70), "pass").unwrap();
+/// grades.insert(Bounds::closed(70, 100), "distinction").unwrap();
+///
+/// assert_eq!(grades.get(&65), Some(&"pass"));
+/// assert_eq!(grades.get(&101), None);
+///
+/// assert!(grades.insert(Bounds::closed(90, 95), "honours").is_err());
+/// ```
+#[derive(PartialEq, Debug, Clone)]
+pub struct IntervalMap<T, V> {
+    entries: Vec<(Bounds<T>, V)>,
+}
+
+impl<T, V> IntervalMap<T, V> {
+
+    /// Creates a new map with no ranges in it.
+    pub fn new() -> Self {
+        IntervalMap { entries: Vec::new() }
+    }
+
+    /// Returns an iterator over the ranges in this map and their values, in
+    /// ascending order of range.
+    pub fn iter(&self) -> impl Iterator<Item=(&Bounds<T>, &V)> {
+        self.entries.iter().map(|e| (&e.0, &e.1))
+    }
+
+    /// Returns the number of ranges in this map.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns whether this map has no ranges in it.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl<T: PartialOrd + Clone, V> IntervalMap<T, V> {
+
+    /// Adds a range to this map with the given value. If the range overlaps
+    /// one that is already in the map, the map is left unchanged, and an
+    /// error is returned.
+    ///
+    /// Empty ranges can never contain a value, so they are not added.
+    pub fn insert(&mut self, range: Bounds<T>, value: V) -> Result<(), OverlapError<T>> {
+        if range.is_empty() {
+            return Ok(());
+        }
+
+        let index = self.entries.partition_point(|e| cmp_lowers(&e.0.lower, &range.lower).is_lt());
+
+        // As the ranges are disjoint and sorted, only the ranges either side
+        // of the new one could overlap it.
+        let neighbours = self.entries[index.saturating_sub(1) ..].iter().take(2);
+        for (existing, _) in neighbours {
+            if existing.intersection(&range).is_some() {
+                return Err(OverlapError { new_range: range, existing_range: existing.clone() });
+            }
+        }
+
+        self.entries.insert(index, (range, value));
+        Ok(())
+    }
+
+    /// Removes the given range from this map, returning its value, or `None`
+    /// if that exact range is not in the map.
+    pub fn remove(&mut self, range: &Bounds<T>) -> Option<V> {
+        let index = self.entries.iter().position(|e| e.0 == *range)?;
+        Some(self.entries.remove(index).1)
+    }
+
+    /// Returns the value for the range that the given value lies within, or
+    /// `None` if it does not lie within any of them.
+    pub fn get(&self, value: &T) -> Option<&V> {
+        self.get_key_value(value).map(|e| e.1)
+    }
+
+    /// Returns the range that the given value lies within, along with its
+    /// value, or `None` if it does not lie within any of them.
+    pub fn get_key_value(&self, value: &T) -> Option<(&Bounds<T>, &V)> {
+        let index = self.entries.partition_point(|e| lower_admits(&e.0.lower, value));
+        let (range, v) = self.entries.get(index.checked_sub(1)?)?;
+
+        if range.contains(value) { Some((range, v)) } else { None }
+    }
+
+    /// Returns the value for the range that the given value lies within.
+    /// Otherwise, returns an `OutOfRangeError` that lists every range
+    /// covered by this map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_check::{Bounds, IntervalMap};
+    ///
+    /// let mut alerts = IntervalMap::new();
+    /// alerts.insert(Bounds::half_open(0, 10), "normal").unwrap();
+    /// alerts.insert(Bounds::half_open(10, 20), "warning").unwrap();
+    /// alerts.insert(Bounds::at_least(50), "critical").unwrap();
+    ///
+    /// assert_eq!(alerts.get_checked(15), Ok(&"warning"));
+    /// assert_eq!(alerts.get_checked(30).unwrap_err().to_string(),
+    ///            "value (30) between ranges (0..20, 50..)");
+    /// ```
+    pub fn get_checked(&self, value: T) -> Result<&V, OutOfRangeError<T>> {
+        if let Some(v) = self.get(&value) {
+            return Ok(v);
+        }
+
+        let covered: RangeSet<T> = self.entries.iter().map(|e| e.0.clone()).collect();
+        Err(covered.error_for(value))
+    }
+}
+
+impl<T, V> Default for IntervalMap<T, V> {
+    fn default() -> Self {
+        IntervalMap::new()
+    }
+}
+
+/// The error that gets returned when a range is added to an `IntervalMap`
+/// that overlaps a range already in it.
+#[derive(PartialEq, Debug, Clone)]
+pub struct OverlapError<T> {
+
+    /// The range that could not be added.
+    pub new_range: Bounds<T>,
+
+    /// The range already in the map that it overlaps.
+    pub existing_range: Bounds<T>,
+}
+
+impl<T: fmt::Debug> ErrorTrait for OverlapError<T> {
+    fn description(&self) -> &str {
+        "range overlaps existing range"
+    }
+}
+
+impl<T: fmt::Debug> fmt::Display for OverlapError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "range ({}) overlaps existing range ({})",
+            self.new_range, self.existing_range)
+    }
+}
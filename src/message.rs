@@ -0,0 +1,471 @@
+use std::fmt;
+use std::ops::Bound;
+
+use bounds::Bounds;
+use check::{ErrorKind, OutOfRangeError};
+
+
+/// A set of phrasings for describing what a value must be, in a particular
+/// language, which are used to turn an `OutOfRangeError` into a message
+/// that can be shown to end users.
+///
+/// Each method is given the range or ranges that the value must be within,
+/// with their values ready to be written using `Display`. Two catalogs come
+/// with this crate, `EnglishCatalog` and `GermanCatalog`; implement this
+/// trait to add another.
+///
+/// # Examples
+///
+/// ```
+/// use range_check::{Bounds, Catalog, Check};
+/// use std::fmt;
+/// use std::ops::Bound;
+///
+/// struct Terse;
+///
+/// impl Catalog for Terse {
+///     fn requirement(&self, f: &mut fmt::Formatter, range: &Bounds<&dyn fmt::Display>) -> fmt::Result {
+///         match (&range.lower, &range.upper) {
+///             (Bound::Included(l), Bound::Included(u))  => write!(f, "{}–{}", l, u),
+///             _                                         => write!(f, "out of range"),
+///         }
+///     }
+///
+///     fn any_of(&self, f: &mut fmt::Formatter, _: &[Bounds<&dyn fmt::Display>]) -> fmt::Result {
+///         write!(f, "out of range")
+///     }
+///
+///     fn no_allowed_values(&self, f: &mut fmt::Formatter) -> fmt::Result {
+///         write!(f, "impossible")
+///     }
+///
+///     fn incomparable(&self, f: &mut fmt::Formatter) -> fmt::Result {
+///         write!(f, "not a number")
+///     }
+/// }
+///
+/// let err = 0.check_range(1 ..= 12).unwrap_err();
+/// assert_eq!(err.message_in(&Terse).to_string(), "1–12");
+/// ```
+pub trait Catalog {
+
+    /// Writes what the value must be for it to lie within the given range,
+    /// such as “must be at least 1”.
+    fn requirement(&self, f: &mut fmt::Formatter, range: &Bounds<&dyn fmt::Display>) -> fmt::Result;
+
+    /// Writes what the value must be for it to lie within any one of the
+    /// given ranges, of which there is more than one.
+    fn any_of(&self, f: &mut fmt::Formatter, ranges: &[Bounds<&dyn fmt::Display>]) -> fmt::Result;
+
+    /// Writes that no value could ever be valid, because the range is
+    /// empty or inverted, or the set of ranges is empty.
+    fn no_allowed_values(&self, f: &mut fmt::Formatter) -> fmt::Result;
+
+    /// Writes that the value cannot be compared with the range at all, such
+    /// as a float that is NaN.
+    fn incomparable(&self, f: &mut fmt::Formatter) -> fmt::Result;
+}
+
+
+/// The catalog of English messages, such as “must be between 1 and 9998”,
+/// “must be at least 0”, or “must be less than 60”.
+#[derive(PartialEq, Debug, Copy, Clone, Default)]
+pub struct EnglishCatalog;
+
+impl EnglishCatalog {
+    fn condition(f: &mut fmt::Formatter, range: &Bounds<&dyn fmt::Display>) -> fmt::Result {
+        match (&range.lower, &range.upper) {
+            (Bound::Included(l), Bound::Included(u)) if is_single(l, u)  => write!(f, "{}", l),
+            (Bound::Included(l), Bound::Included(u))                     => write!(f, "between {} and {}", l, u),
+            (Bound::Included(l), Bound::Excluded(u))                     => write!(f, "at least {} and less than {}", l, u),
+            (Bound::Excluded(l), Bound::Included(u))                     => write!(f, "greater than {} and at most {}", l, u),
+            (Bound::Excluded(l), Bound::Excluded(u))                     => write!(f, "greater than {} and less than {}", l, u),
+            (Bound::Included(l), Bound::Unbounded)                       => write!(f, "at least {}", l),
+            (Bound::Excluded(l), Bound::Unbounded)                       => write!(f, "greater than {}", l),
+            (Bound::Unbounded,   Bound::Included(u))                     => write!(f, "at most {}", u),
+            (Bound::Unbounded,   Bound::Excluded(u))                     => write!(f, "less than {}", u),
+            (Bound::Unbounded,   Bound::Unbounded)                       => write!(f, "any value"),
+        }
+    }
+}
+
+impl Catalog for EnglishCatalog {
+    fn requirement(&self, f: &mut fmt::Formatter, range: &Bounds<&dyn fmt::Display>) -> fmt::Result {
+        write!(f, "must be ")?;
+        Self::condition(f, range)
+    }
+
+    fn any_of(&self, f: &mut fmt::Formatter, ranges: &[Bounds<&dyn fmt::Display>]) -> fmt::Result {
+        write!(f, "must be ")?;
+
+        for (i, range) in ranges.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", or ")?;
+            }
+
+            Self::condition(f, range)?;
+        }
+
+        Ok(())
+    }
+
+    fn no_allowed_values(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "cannot be any value, as the range is empty")
+    }
+
+    fn incomparable(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "must be a comparable value")
+    }
+}
+
+
+/// The catalog of German messages, such as “muss zwischen 1 und 9998
+/// liegen”, “muss mindestens 0 sein”, or “muss kleiner als 60 sein”.
+#[derive(PartialEq, Debug, Copy, Clone, Default)]
+pub struct GermanCatalog;
+
+impl GermanCatalog {
+    fn condition(f: &mut fmt::Formatter, range: &Bounds<&dyn fmt::Display>) -> fmt::Result {
+        match (&range.lower, &range.upper) {
+            (Bound::Included(l), Bound::Included(u)) if is_single(l, u)  => write!(f, "{} sein", l),
+            (Bound::Included(l), Bound::Included(u))                     => write!(f, "zwischen {} und {} liegen", l, u),
+            (Bound::Included(l), Bound::Excluded(u))                     => write!(f, "mindestens {} und kleiner als {} sein", l, u),
+            (Bound::Excluded(l), Bound::Included(u))                     => write!(f, "größer als {} und höchstens {} sein", l, u),
+            (Bound::Excluded(l), Bound::Excluded(u))                     => write!(f, "größer als {} und kleiner als {} sein", l, u),
+            (Bound::Included(l), Bound::Unbounded)                       => write!(f, "mindestens {} sein", l),
+            (Bound::Excluded(l), Bound::Unbounded)                       => write!(f, "größer als {} sein", l),
+            (Bound::Unbounded,   Bound::Included(u))                     => write!(f, "höchstens {} sein", u),
+            (Bound::Unbounded,   Bound::Excluded(u))                     => write!(f, "kleiner als {} sein", u),
+            (Bound::Unbounded,   Bound::Unbounded)                       => write!(f, "ein beliebiger Wert sein"),
+        }
+    }
+}
+
+impl Catalog for GermanCatalog {
+    fn requirement(&self, f: &mut fmt::Formatter, range: &Bounds<&dyn fmt::Display>) -> fmt::Result {
+        write!(f, "muss ")?;
+        Self::condition(f, range)
+    }
+
+    fn any_of(&self, f: &mut fmt::Formatter, ranges: &[Bounds<&dyn fmt::Display>]) -> fmt::Result {
+        write!(f, "muss ")?;
+
+        for (i, range) in ranges.iter().enumerate() {
+            if i > 0 {
+                write!(f, " oder ")?;
+            }
+
+            Self::condition(f, range)?;
+        }
+
+        Ok(())
+    }
+
+    fn no_allowed_values(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "kann keinen Wert annehmen, da der Bereich leer ist")
+    }
+
+    fn incomparable(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "muss ein vergleichbarer Wert sein")
+    }
+}
+
+
+/// Whether the two ends of a closed range are the same value, so it can be
+/// written as that value alone. The values can only be compared by how they
+/// are written, and if they are written the same, the range reads as one.
+fn is_single(lower: &dyn fmt::Display, upper: &dyn fmt::Display) -> bool {
+    lower.to_string() == upper.to_string()
+}
+
+
+/// Trait for types where every value has a next and a previous value, such
+/// as integers, so that a range with an excluded bound can be described
+/// using the value next to it instead: `1..9999` is the same range as
+/// `1..=9998`.
+///
+/// Floats step to the next representable float, which is also how `Coerce`
+/// moves a value off an excluded bound.
+pub trait Discrete: Sized {
+
+    /// The next value up, or `None` if this is the highest value.
+    fn successor(&self) -> Option<Self>;
+
+    /// The next value down, or `None` if this is the lowest value.
+    fn predecessor(&self) -> Option<Self>;
+}
+
+macro_rules! impl_discrete {
+    ($($t:ty),*) => {
+        $(
+            impl Discrete for $t {
+                fn successor(&self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                fn predecessor(&self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
+            }
+        )*
+    };
+}
+
+impl_discrete! { i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize }
+
+macro_rules! impl_discrete_float {
+    ($($t:ty),*) => {
+        $(
+            impl Discrete for $t {
+                fn successor(&self) -> Option<Self> {
+                    if self.is_nan() || *self == <$t>::INFINITY {
+                        None
+                    }
+                    else if *self == 0.0 {
+                        Some(<$t>::from_bits(1))
+                    }
+                    else if *self > 0.0 {
+                        Some(<$t>::from_bits(self.to_bits() + 1))
+                    }
+                    else {
+                        Some(<$t>::from_bits(self.to_bits() - 1))
+                    }
+                }
+
+                fn predecessor(&self) -> Option<Self> {
+                    (-self).successor().map(|n| -n)
+                }
+            }
+        )*
+    };
+}
+
+impl_discrete_float! { f32, f64 }
+
+// The surrogate code points are skipped over, as they are not chars.
+impl Discrete for char {
+    fn successor(&self) -> Option<Self> {
+        match *self {
+            '\u{D7FF}'  => Some('\u{E000}'),
+            c           => std::char::from_u32(c as u32 + 1),
+        }
+    }
+
+    fn predecessor(&self) -> Option<Self> {
+        match *self {
+            '\u{E000}'  => Some('\u{D7FF}'),
+            '\0'        => None,
+            c           => std::char::from_u32(c as u32 - 1),
+        }
+    }
+}
+
+
+impl<T> OutOfRangeError<T> {
+
+    /// Returns a value that displays what the value should have been, in
+    /// English, for showing to end users. Values are written using their
+    /// `Display` implementation, and the path, if there is one, is written
+    /// first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_check::Check;
+    ///
+    /// let err = 61.check_range_named("minute", 0..60).unwrap_err();
+    /// assert_eq!(err.message().to_string(), "minute: must be at least 0 and less than 60");
+    ///
+    /// let err = 24680.check_range(1..9999).unwrap_err();
+    /// assert_eq!(err.message().to_string(), "must be at least 1 and less than 9999");
+    /// assert_eq!(err.message().inclusive().to_string(), "must be between 1 and 9998");
+    /// ```
+    pub fn message(&self) -> Message<'_, T> {
+        self.message_in(&EnglishCatalog)
+    }
+
+    /// Returns a value that displays what the value should have been, using
+    /// the phrasings in the given catalog.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_check::{Check, GermanCatalog};
+    ///
+    /// let err = (-1).check_range(0..).unwrap_err();
+    /// assert_eq!(err.message_in(&GermanCatalog).to_string(), "muss mindestens 0 sein");
+    /// ```
+    pub fn message_in<'a>(&'a self, catalog: &'a dyn Catalog) -> Message<'a, T> {
+        Message { error: self, catalog, steps: None }
+    }
+}
+
+/// The message for an `OutOfRangeError` to show to end users, returned by
+/// `OutOfRangeError::message`.
+pub struct Message<'a, T> {
+    error: &'a OutOfRangeError<T>,
+    catalog: &'a dyn Catalog,
+    steps: Option<Steps<T>>,
+}
+
+/// The functions an inclusive message uses to replace excluded bounds with
+/// the values next to them, and to tell whether the range that results has
+/// any values left in it.
+struct Steps<T> {
+    successor: fn(&T) -> Option<T>,
+    predecessor: fn(&T) -> Option<T>,
+    less: fn(&T, &T) -> bool,
+}
+
+impl<'a, T: Discrete + PartialOrd> Message<'a, T> {
+
+    /// Describes excluded bounds using the value next to them instead, so
+    /// that `0..60` is described as being between 0 and 59, rather than at
+    /// least 0 and less than 60. A range such as `0<..1` that has no values
+    /// left once both bounds are stepped is described as empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_check::{Bounds, Check};
+    ///
+    /// let err = 5.check_range(Bounds::open(0, 1)).unwrap_err();
+    /// assert_eq!(err.message().inclusive().to_string(), "cannot be any value, as the range is empty");
+    /// ```
+    pub fn inclusive(self) -> Self {
+        let steps = Steps { successor: T::successor, predecessor: T::predecessor, less: T::lt };
+        Message { steps: Some(steps), ..self }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Message<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Message")
+         .field("error", &self.error)
+         .field("inclusive", &self.steps.is_some())
+         .finish_non_exhaustive()
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Message<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(path) = self.error.path.as_deref().filter(|path| ! path.is_empty()) {
+            write!(f, "{}: ", path)?;
+        }
+
+        match &self.error.kind {
+            ErrorKind::Outside => {
+                let range = self.shown(&self.error.allowed_range);
+                if self.is_crossed(&range) {
+                    self.catalog.no_allowed_values(f)
+                }
+                else {
+                    self.catalog.requirement(f, &as_dyn(&range))
+                }
+            }
+            ErrorKind::OutsideSet(ranges) if ! ranges.is_empty() => {
+                let ranges = ranges.iter().map(|r| self.shown(r)).filter(|r| ! self.is_crossed(r)).collect::<Vec<_>>();
+                let ranges = ranges.iter().map(as_dyn).collect::<Vec<_>>();
+                match &ranges[..] {
+                    []         => self.catalog.no_allowed_values(f),
+                    [ range ]  => self.catalog.requirement(f, range),
+                    _          => self.catalog.any_of(f, &ranges),
+                }
+            }
+            ErrorKind::Incomparable => {
+                self.catalog.incomparable(f)
+            }
+            ErrorKind::OutsideSet(_) | ErrorKind::EmptyRange | ErrorKind::InvertedRange => {
+                self.catalog.no_allowed_values(f)
+            }
+        }
+    }
+}
+
+impl<'a, T> Message<'a, T> {
+
+    /// Borrows the values out of the given range, replacing excluded
+    /// bounds with the values next to them if this message is inclusive.
+    fn shown(&self, range: &'a Bounds<T>) -> Bounds<Shown<'a, T>> {
+        let steps = match &self.steps {
+            Some(steps)  => steps,
+            None         => return Bounds { lower: shown(&range.lower), upper: shown(&range.upper) },
+        };
+
+        let lower = match &range.lower {
+            Bound::Excluded(n)  => (steps.successor)(n).map(|n| Bound::Included(Shown::Stepped(n))),
+            _                   => None,
+        };
+
+        let upper = match &range.upper {
+            Bound::Excluded(n)  => (steps.predecessor)(n).map(|n| Bound::Included(Shown::Stepped(n))),
+            _                   => None,
+        };
+
+        Bounds {
+            lower: lower.unwrap_or_else(|| shown(&range.lower)),
+            upper: upper.unwrap_or_else(|| shown(&range.upper)),
+        }
+    }
+
+    /// Returns whether stepping the bounds of a range has left it with no
+    /// values in it, such as when `0<..1` becomes `1..=0`.
+    fn is_crossed(&self, range: &Bounds<Shown<'_, T>>) -> bool {
+        let less = match &self.steps {
+            Some(steps)  => steps.less,
+            None         => return false,
+        };
+
+        match (&range.lower, &range.upper) {
+            (Bound::Unbounded, _) | (_, Bound::Unbounded)  => false,
+            (Bound::Included(l), Bound::Included(u))        => less(u.value(), l.value()),
+            (Bound::Included(l), Bound::Excluded(u)) |
+            (Bound::Excluded(l), Bound::Included(u)) |
+            (Bound::Excluded(l), Bound::Excluded(u))        => ! less(l.value(), u.value()),
+        }
+    }
+}
+
+/// A value from a range, or the value next to it.
+enum Shown<'a, T> {
+    Borrowed(&'a T),
+    Stepped(T),
+}
+
+impl<T> Shown<'_, T> {
+    fn value(&self) -> &T {
+        match self {
+            Shown::Borrowed(n)  => n,
+            Shown::Stepped(n)   => n,
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Shown<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Shown::Borrowed(n)  => n.fmt(f),
+            Shown::Stepped(n)   => n.fmt(f),
+        }
+    }
+}
+
+fn shown<T>(bound: &Bound<T>) -> Bound<Shown<'_, T>> {
+    match bound {
+        Bound::Included(n)  => Bound::Included(Shown::Borrowed(n)),
+        Bound::Excluded(n)  => Bound::Excluded(Shown::Borrowed(n)),
+        Bound::Unbounded    => Bound::Unbounded,
+    }
+}
+
+fn as_dyn<'a, T: fmt::Display>(range: &'a Bounds<Shown<'_, T>>) -> Bounds<&'a dyn fmt::Display> {
+    Bounds { lower: dyn_bound(&range.lower), upper: dyn_bound(&range.upper) }
+}
+
+fn dyn_bound<'a, T: fmt::Display>(bound: &'a Bound<Shown<'_, T>>) -> Bound<&'a dyn fmt::Display> {
+    match bound {
+        Bound::Included(n)  => Bound::Included(n),
+        Bound::Excluded(n)  => Bound::Excluded(n),
+        Bound::Unbounded    => Bound::Unbounded,
+    }
+}
@@ -0,0 +1,288 @@
+use std::cmp::Ordering;
+use std::error::Error as ErrorTrait;
+use std::fmt;
+use std::ops::{Bound, RangeBounds};
+
+use bounds::Bounds;
+use check::{ErrorKind, Violation, write_message};
+
+
+
+/// Trait for checking a primitive number against a range of numbers of a
+/// different primitive type.
+///
+/// The comparisons are exact: rather than casting one number to the other’s
+/// type, which can wrap, saturate, or round, the two are compared by their
+/// mathematical values. So `-1_i32` is below `0_u64`, `u64::MAX` is above
+/// `i64::MAX`, and `9007199254740993_i64` is above `9007199254740992.0_f64`,
+/// even though casting it to `f64` would make them equal.
+///
+/// # Examples
+///
+/// ```
+/// use range_check::CheckMixed;
+///
+/// let length: u64 = 3_000_000_000;
+/// let limit: i32 = i32::MAX;
+///
+/// assert!(length.check_range_mixed(0 ..= limit).is_err());
+/// assert!((-1_i32).check_range_mixed(0_u64 .. 10).is_err());
+/// assert_eq!(7_u8.check_range_mixed(0.5_f32 .. 7.5), Ok(7));
+/// ```
+pub trait CheckMixed: Sized + Copy {
+
+    /// Checks whether `self` is within the given range of values of another
+    /// type. If it is, re-returns `self`. Otherwise, returns an `Error` that
+    /// contains both the value and the range, each with its original type.
+    fn check_range_mixed<U, R>(self, range: R) -> Result<Self, MixedOutOfRangeError<Self, U>>
+    where Self: ExactCmp<U>,
+          U: Copy + PartialOrd,
+          R: RangeBounds<U>;
+}
+
+impl<T: Copy> CheckMixed for T {
+    fn check_range_mixed<U, R>(self, range: R) -> Result<Self, MixedOutOfRangeError<Self, U>>
+    where Self: ExactCmp<U>,
+          U: Copy + PartialOrd,
+          R: RangeBounds<U>,
+    {
+        let allowed_range = Bounds::from_range_bounds(&range);
+
+        match position(&self, &allowed_range) {
+            Some(Ordering::Equal) => Ok(self),
+            position => {
+                let kind = if position.is_none()               { ErrorKind::Incomparable }
+                      else if allowed_range.is_inverted()      { ErrorKind::InvertedRange }
+                      else if allowed_range.is_empty()         { ErrorKind::EmptyRange }
+                      else                                     { ErrorKind::Outside };
+
+                Err(MixedOutOfRangeError { allowed_range, outside_value: self, kind })
+            }
+        }
+    }
+}
+
+/// Works out whether a value is below (`Less`), within (`Equal`), or above
+/// (`Greater`) a range, or `None` if it cannot be compared with its bounds.
+fn position<T: ExactCmp<U>, U>(value: &T, bounds: &Bounds<U>) -> Option<Ordering> {
+    let below = match &bounds.lower {
+        Bound::Unbounded    => false,
+        Bound::Included(l)  => value.exact_cmp(l)? == Ordering::Less,
+        Bound::Excluded(l)  => value.exact_cmp(l)? != Ordering::Greater,
+    };
+
+    let above = match &bounds.upper {
+        Bound::Unbounded    => false,
+        Bound::Included(u)  => value.exact_cmp(u)? == Ordering::Greater,
+        Bound::Excluded(u)  => value.exact_cmp(u)? != Ordering::Less,
+    };
+
+    // NaN cannot even be compared with an unbounded range.
+    if ! value.is_comparable() {
+        None
+    }
+    else if below {
+        Some(Ordering::Less)
+    }
+    else if above {
+        Some(Ordering::Greater)
+    }
+    else {
+        Some(Ordering::Equal)
+    }
+}
+
+
+/// The error that gets thrown when a `check_range_mixed` fails, holding the
+/// value and the range with their original, different, types.
+#[derive(PartialEq, Debug, Clone)]
+pub struct MixedOutOfRangeError<T, U> {
+
+    /// The bounds of the range that was searched.
+    pub allowed_range: Bounds<U>,
+
+    /// The value that lies outside of the range.
+    pub outside_value: T,
+
+    /// Why the value does not lie within the range.
+    pub kind: ErrorKind<U>,
+}
+
+impl<T: ExactCmp<U>, U> MixedOutOfRangeError<T, U> {
+
+    /// Returns which side of the allowed range the value lies on.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_check::{CheckMixed, Violation};
+    ///
+    /// let err = u64::MAX.check_range_mixed(0 .. i64::MAX).unwrap_err();
+    /// assert_eq!(err.violation(), Violation::AboveUpper);
+    /// ```
+    pub fn violation(&self) -> Violation {
+        match position(&self.outside_value, &self.allowed_range) {
+            Some(Ordering::Less)     => Violation::BelowLower,
+            Some(Ordering::Greater)  => Violation::AboveUpper,
+            Some(Ordering::Equal)    => Violation::InGap,
+            None                     => Violation::Incomparable,
+        }
+    }
+}
+
+impl<T: fmt::Debug + ExactCmp<U>, U: fmt::Debug> fmt::Display for MixedOutOfRangeError<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_message(f, None, &self.outside_value, &self.allowed_range, &self.kind, self.violation(), &|f, range| write!(f, "{}", range))
+    }
+}
+
+impl<T: fmt::Debug + ExactCmp<U>, U: fmt::Debug> ErrorTrait for MixedOutOfRangeError<T, U> {
+    fn description(&self) -> &str {
+        "value outside of range"
+    }
+}
+
+
+/// Exact comparisons between two primitive number types, for naming in the
+/// bounds of functions that call `check_range_mixed`.
+///
+/// It is implemented for every pair of primitive number types through a
+/// private trait that turns each number into its exact value, so it cannot
+/// be implemented for any other type.
+///
+/// # Examples
+///
+/// ```
+/// use range_check::{CheckMixed, ExactCmp};
+///
+/// fn is_percentage<T: ExactCmp<u8> + Copy>(value: T) -> bool {
+///     value.check_range_mixed(0_u8 ..= 100).is_ok()
+/// }
+///
+/// assert!(is_percentage(99.5_f32));
+/// assert!(! is_percentage(-1_i64));
+/// ```
+pub trait ExactCmp<U>: exact::ToExact {
+
+    /// Compares the mathematical values of two numbers, returning `None` if
+    /// either is NaN.
+    fn exact_cmp(&self, other: &U) -> Option<Ordering>;
+
+    /// Whether this number can be compared at all.
+    fn is_comparable(&self) -> bool;
+}
+
+
+mod exact {
+    use std::cmp::Ordering;
+
+    use super::ExactCmp;
+
+    /// Any primitive integer, stored as a sign and a magnitude, which is
+    /// enough to hold everything from `i128::MIN` to `u128::MAX`.
+    #[derive(PartialEq, Eq, Debug, Copy, Clone)]
+    pub struct Int {
+        negative: bool,
+        magnitude: u128,
+    }
+
+    /// The exact value of any primitive number. Every `f32` can be stored
+    /// exactly as an `f64`.
+    #[derive(Debug, Copy, Clone)]
+    pub enum Exact {
+        Int(Int),
+        Float(f64),
+    }
+
+    /// Primitive number types that can be turned into exact values.
+    pub trait ToExact: Copy {
+        fn to_exact(self) -> Exact;
+    }
+
+    impl<T: ToExact, U: ToExact> ExactCmp<U> for T {
+        fn exact_cmp(&self, other: &U) -> Option<Ordering> {
+            match (self.to_exact(), other.to_exact()) {
+                (Exact::Int(a),   Exact::Int(b))    => Some(cmp_ints(a, b)),
+                (Exact::Float(a), Exact::Float(b))  => a.partial_cmp(&b),
+                (Exact::Int(a),   Exact::Float(b))  => cmp_int_float(a, b),
+                (Exact::Float(a), Exact::Int(b))    => cmp_int_float(b, a).map(Ordering::reverse),
+            }
+        }
+
+        fn is_comparable(&self) -> bool {
+            match self.to_exact() {
+                Exact::Int(_)    => true,
+                Exact::Float(f)  => ! f.is_nan(),
+            }
+        }
+    }
+
+    macro_rules! impl_to_exact {
+        (signed: $($t:ty),*) => {
+            $(
+                impl ToExact for $t {
+                    #[allow(trivial_numeric_casts)]
+                    fn to_exact(self) -> Exact {
+                        Exact::Int(Int { negative: self < 0, magnitude: (self as i128).unsigned_abs() })
+                    }
+                }
+            )*
+        };
+        (unsigned: $($t:ty),*) => {
+            $(
+                impl ToExact for $t {
+                    #[allow(trivial_numeric_casts)]
+                    fn to_exact(self) -> Exact {
+                        Exact::Int(Int { negative: false, magnitude: self as u128 })
+                    }
+                }
+            )*
+        };
+        (float: $($t:ty),*) => {
+            $(
+                impl ToExact for $t {
+                    #[allow(trivial_numeric_casts)]
+                    fn to_exact(self) -> Exact {
+                        Exact::Float(self as f64)
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_to_exact! { signed: i8, i16, i32, i64, i128, isize }
+    impl_to_exact! { unsigned: u8, u16, u32, u64, u128, usize }
+    impl_to_exact! { float: f32, f64 }
+
+    fn cmp_ints(a: Int, b: Int) -> Ordering {
+        match (a.negative, b.negative) {
+            (false, false)  => a.magnitude.cmp(&b.magnitude),
+            (true,  true)   => b.magnitude.cmp(&a.magnitude),
+            (false, true)   => Ordering::Greater,
+            (true,  false)  => Ordering::Less,
+        }
+    }
+
+    /// Compares an integer with a float by comparing it with the whole part
+    /// of the float first, then with the fractional part if they are equal.
+    fn cmp_int_float(int: Int, float: f64) -> Option<Ordering> {
+        if float.is_nan() {
+            return None;
+        }
+
+        // Floats this large, including infinity, are beyond every integer.
+        let whole = float.trunc();
+        if whole.abs() >= 2_f64.powi(128) {
+            return Some(if float > 0.0 { Ordering::Less } else { Ordering::Greater });
+        }
+
+        let whole_int = Int { negative: whole < 0.0, magnitude: whole.abs() as u128 };
+        let fraction = float - whole;
+
+        Some(cmp_ints(int, whole_int).then_with(|| {
+            if fraction > 0.0       { Ordering::Less }
+            else if fraction < 0.0  { Ordering::Greater }
+            else                    { Ordering::Equal }
+        }))
+    }
+}
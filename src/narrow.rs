@@ -0,0 +1,163 @@
+use bounds::Bounds;
+use check::{Check, OutOfRangeError};
+
+
+/// Trait for converting a number into another primitive number type,
+/// checking that it lies between that type’s `MIN` and `MAX` first.
+///
+/// Unlike `TryFrom`, a failed conversion returns an `OutOfRangeError` that
+/// holds the range of values that would have fit, expressed in the type
+/// being converted from.
+///
+/// This is implemented for every pair of primitive integer and float types.
+/// The check is exact, even where the bounds of one type cannot be stored
+/// in the other: a float is converted to an integer only if its value lies
+/// between the integer type’s bounds, after which any fractional part is
+/// dropped, as with `as`. Integers converted to floats are rounded to the
+/// nearest float. NaN cannot be compared with any range, and infinity is
+/// outside the range of every type, so neither can be converted.
+///
+/// # Examples
+///
+/// ```
+/// use range_check::CheckInto;
+///
+/// assert_eq!(200_i64.check_into::<u8>(), Ok(200_u8));
+/// assert_eq!(300_i64.check_into::<u8>().unwrap_err().to_string(),
+///            "value (300) above range (0..=255)");
+///
+/// assert_eq!(12.75_f64.check_into::<i8>(), Ok(12));
+/// assert!(1e40_f64.check_into::<f32>().is_err());
+/// ```
+pub trait CheckInto: Sized {
+
+    /// Converts `self` into the type `U` if it lies within the range of that
+    /// type. Otherwise, returns an `Error` that contains both the value and
+    /// the range.
+    fn check_into<U>(self) -> Result<U, OutOfRangeError<Self>>
+    where Self: RangeOf<U>;
+}
+
+impl<T: PartialOrd + Copy> CheckInto for T {
+    fn check_into<U>(self) -> Result<U, OutOfRangeError<Self>>
+    where Self: RangeOf<U>
+    {
+        self.check_range(Self::range_of()).map(Self::convert)
+    }
+}
+
+
+/// The range of one number type that can be converted into another, for
+/// naming in the bounds of functions that call `check_into`.
+///
+/// This trait is sealed, as it is only implemented for the primitive number
+/// types.
+///
+/// # Examples
+///
+/// ```
+/// use range_check::{CheckInto, OutOfRangeError, RangeOf};
+///
+/// fn to_byte<T: RangeOf<u8> + PartialOrd + Copy>(value: T) -> Result<u8, OutOfRangeError<T>> {
+///     value.check_into()
+/// }
+///
+/// assert_eq!(to_byte(200_i32), Ok(200));
+/// assert!(to_byte(-1.5_f64).is_err());
+/// ```
+pub trait RangeOf<U>: Sized + sealed::Sealed<U> {
+
+    /// The values of this type that lie between the other type’s `MIN` and
+    /// `MAX`.
+    fn range_of() -> Bounds<Self>;
+
+    /// Converts a value within that range into the other type.
+    fn convert(self) -> U;
+}
+
+mod sealed {
+    pub trait Sealed<U> {}
+}
+
+
+macro_rules! impl_range_of {
+    ($kind:ident: $($t:ty),* => $us:tt) => {
+        $( impl_range_of!(@each $kind: $t => $us); )*
+    };
+
+    (@each $kind:ident: $t:ty => [ $($u:ty),* ]) => {
+        $(
+            impl sealed::Sealed<$u> for $t {}
+
+            impl RangeOf<$u> for $t {
+                #[allow(trivial_numeric_casts)]
+                fn range_of() -> Bounds<$t> {
+                    impl_range_of!(@range $kind: $t => $u)
+                }
+
+                #[allow(trivial_numeric_casts)]
+                fn convert(self) -> $u {
+                    self as $u
+                }
+            }
+        )*
+    };
+
+    // The other type’s bounds either fit in this one, or lie beyond its own
+    // bounds in the same direction.
+    (@range int_to_int: $t:ty => $u:ty) => {{
+        use std::convert::TryFrom;
+        let lower = <$t>::try_from(<$u>::MIN).unwrap_or(<$t>::MIN);
+        let upper = <$t>::try_from(<$u>::MAX).unwrap_or(<$t>::MAX);
+        Bounds::closed(lower, upper)
+    }};
+
+    // Float-to-integer casts saturate, and every float bound is a whole
+    // number, so casting the bounds is exact.
+    (@range int_to_float: $t:ty => $u:ty) => {{
+        Bounds::closed(<$u>::MIN as $t, <$u>::MAX as $t)
+    }};
+
+    // An integer’s minimum is zero or a power of two, so it can be stored
+    // exactly. Its maximum is one less than a power of two, which gets
+    // rounded up to that power of two if the float is not precise enough,
+    // in which case the float just below it is used instead.
+    (@range float_to_int: $t:ty => $u:ty) => {{
+        let lower = <$u>::MIN as $t;
+        let upper = <$u>::MAX as $t;
+        let power = (<$u>::MAX / 2 + 1) as $t * 2.0;
+
+        if upper < power {
+            Bounds::closed(lower, upper)
+        }
+        else {
+            Bounds::closed(lower, <$t>::from_bits(power.to_bits() - 1))
+        }
+    }};
+
+    // Casting between floats rounds to the nearest value or to infinity, so
+    // the bounds need clamping to this type’s finite values.
+    (@range float_to_float: $t:ty => $u:ty) => {{
+        Bounds::closed((<$u>::MIN as $t).max(<$t>::MIN), (<$u>::MAX as $t).min(<$t>::MAX))
+    }};
+}
+
+impl_range_of! { int_to_int:
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize =>
+    [ i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize ]
+}
+
+impl_range_of! { int_to_float:
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize =>
+    [ f32, f64 ]
+}
+
+impl_range_of! { float_to_int:
+    f32, f64 =>
+    [ i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize ]
+}
+
+impl_range_of! { float_to_float:
+    f32, f64 =>
+    [ f32, f64 ]
+}
@@ -0,0 +1,140 @@
+use std::error::Error as ErrorTrait;
+use std::fmt;
+use std::ops::Bound;
+use std::str::FromStr;
+
+use bounds::Bounds;
+
+
+/// Parses a range from the same text that `Bounds` is displayed as, which
+/// is Rust’s range syntax: `1..5`, `1..=5`, `..5`, `..=5`, `1..`, or `..`.
+/// A lower bound followed by `<`, such as `1<..5`, is excluded from the
+/// range.
+///
+/// Whitespace is allowed around each value, so `1 .. 5` parses too.
+///
+/// Each value is parsed using its own `FromStr` implementation. As `Bounds`
+/// is displayed using each value’s `Debug` implementation, values that
+/// display differently with `Debug`, such as strings, do not round-trip.
+///
+/// # Examples
+///
+/// ```
+/// use range_check::Bounds;
+///
+/// let hours: Bounds<u8> = "0..24".parse().unwrap();
+/// assert_eq!(hours, Bounds::half_open(0, 24));
+///
+/// let temperature: Bounds<f64> = "-40.0 ..= 85.0".parse().unwrap();
+/// assert_eq!(temperature, Bounds::closed(-40.0, 85.0));
+///
+/// let err = "0..2x".parse::<Bounds<u8>>().unwrap_err();
+/// assert_eq!(err.offset, 3);
+/// assert_eq!(err.to_string(), "invalid value at byte 3: invalid digit found in string");
+/// ```
+impl<T: FromStr> FromStr for Bounds<T> {
+    type Err = ParseBoundsError<T::Err>;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let dots = match input.find("..") {
+            Some(dots)  => dots,
+            None        => return Err(ParseBoundsError { offset: input.len(), kind: ParseErrorKind::MissingDots }),
+        };
+
+        let lower_text = input[.. dots].trim_end();
+        let lower = if let Some(value) = lower_text.strip_suffix('<') {
+            match parse_value(value, 0)? {
+                Some(value)  => Bound::Excluded(value),
+                None         => return Err(ParseBoundsError { offset: 0, kind: ParseErrorKind::MissingValue }),
+            }
+        }
+        else {
+            parse_value(lower_text, 0)?.map_or(Bound::Unbounded, Bound::Included)
+        };
+
+        let after_dots = dots + 2;
+        let upper = if input[after_dots ..].starts_with('=') {
+            match parse_value(&input[after_dots + 1 ..], after_dots + 1)? {
+                Some(value)  => Bound::Included(value),
+                None         => return Err(ParseBoundsError { offset: input.len(), kind: ParseErrorKind::MissingValue }),
+            }
+        }
+        else {
+            parse_value(&input[after_dots ..], after_dots)?.map_or(Bound::Unbounded, Bound::Excluded)
+        };
+
+        Ok(Bounds { lower, upper })
+    }
+}
+
+/// Parses the value in the given piece of text, which begins at the given
+/// offset in the input, returning `None` if there is no value there.
+fn parse_value<T: FromStr>(text: &str, offset: usize) -> Result<Option<T>, ParseBoundsError<T::Err>> {
+    let trimmed = text.trim_start();
+    let offset = offset + (text.len() - trimmed.len());
+    let trimmed = trimmed.trim_end();
+
+    if trimmed.is_empty() {
+        return Ok(None);
+    }
+
+    match trimmed.parse() {
+        Ok(value)  => Ok(Some(value)),
+        Err(e)     => Err(ParseBoundsError { offset, kind: ParseErrorKind::InvalidValue(e) }),
+    }
+}
+
+
+/// The error returned when a `Bounds` cannot be parsed from a string.
+#[derive(PartialEq, Debug, Clone)]
+pub struct ParseBoundsError<E> {
+
+    /// The byte offset in the input where the problem was found.
+    pub offset: usize,
+
+    /// What the problem was.
+    pub kind: ParseErrorKind<E>,
+}
+
+/// The reason a `Bounds` could not be parsed.
+#[derive(PartialEq, Debug, Clone)]
+pub enum ParseErrorKind<E> {
+
+    /// The input does not contain `..`, so it is not a range.
+    MissingDots,
+
+    /// A `<` or `=` next to the `..` is not next to a value, such
+    /// as in `1..=`, or one side of an interval is blank.
+    MissingValue,
+
+    /// An interval does not start or end with a bracket or parenthesis.
+    MissingBracket,
+
+    /// An interval does not contain the separator between its values.
+    MissingSeparator,
+
+    /// A value could not be parsed. This contains the error returned by its
+    /// `FromStr` implementation.
+    InvalidValue(E),
+}
+
+impl<E: fmt::Display> fmt::Display for ParseBoundsError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.kind {
+            ParseErrorKind::MissingDots      => write!(f, "expected `..` at byte {}", self.offset),
+            ParseErrorKind::MissingValue     => write!(f, "missing value at byte {}", self.offset),
+            ParseErrorKind::MissingBracket   => write!(f, "expected bracket at byte {}", self.offset),
+            ParseErrorKind::MissingSeparator => write!(f, "expected separator at byte {}", self.offset),
+            ParseErrorKind::InvalidValue(e)  => write!(f, "invalid value at byte {}: {}", self.offset, e),
+        }
+    }
+}
+
+impl<E: ErrorTrait + 'static> ErrorTrait for ParseBoundsError<E> {
+    fn source(&self) -> Option<&(dyn ErrorTrait + 'static)> {
+        match &self.kind {
+            ParseErrorKind::InvalidValue(e)  => Some(e),
+            _                                => None,
+        }
+    }
+}
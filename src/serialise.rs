@@ -0,0 +1,110 @@
+use std::ops::Bound;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use bounds::Bounds;
+
+
+/// One end of a range, as it gets serialised.
+#[derive(Serialize, Deserialize)]
+struct Endpoint<T> {
+    value: T,
+    inclusive: bool,
+}
+
+/// A range, as it gets serialised.
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Bounds")]
+struct Ends<T> {
+    lower: Option<Endpoint<T>>,
+    upper: Option<Endpoint<T>>,
+}
+
+fn to_endpoint<T>(bound: &Bound<T>) -> Option<Endpoint<&T>> {
+    match bound {
+        Bound::Included(value)  => Some(Endpoint { value, inclusive: true }),
+        Bound::Excluded(value)  => Some(Endpoint { value, inclusive: false }),
+        Bound::Unbounded        => None,
+    }
+}
+
+fn from_endpoint<T>(endpoint: Option<Endpoint<T>>) -> Bound<T> {
+    match endpoint {
+        Some(Endpoint { value, inclusive: true })   => Bound::Included(value),
+        Some(Endpoint { value, inclusive: false })  => Bound::Excluded(value),
+        None                                        => Bound::Unbounded,
+    }
+}
+
+impl<T: Serialize> Serialize for Bounds<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let ends = Ends { lower: to_endpoint(&self.lower), upper: to_endpoint(&self.upper) };
+        ends.serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Bounds<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let ends = Ends::deserialize(deserializer)?;
+        Ok(Bounds { lower: from_endpoint(ends.lower), upper: from_endpoint(ends.upper) })
+    }
+}
+
+
+/// Serialises a `Bounds` as a string in Rust’s range syntax, such as
+/// `"0..24"`, instead of as a structure, for use with Serde’s `with`
+/// attribute.
+///
+/// The string is the `Bounds`’s `Display` form, and it is read back using
+/// its `FromStr` implementation, so the values in it must round-trip
+/// through `Debug` and `FromStr`, as numbers do.
+///
+/// # Examples
+///
+/// ```
+/// # extern crate serde;
+/// # extern crate serde_json;
+/// # extern crate range_check;
+/// use range_check::Bounds;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize, PartialEq, Debug)]
+/// struct Setting {
+///     #[serde(with = "range_check::bounds_as_string")]
+///     hours: Bounds<u8>,
+/// }
+///
+/// # fn main() {
+/// let setting = Setting { hours: Bounds::half_open(9, 17) };
+/// let json = serde_json::to_string(&setting).unwrap();
+/// assert_eq!(json, r#"{"hours":"9..17"}"#);
+/// assert_eq!(serde_json::from_str::<Setting>(&json).unwrap(), setting);
+/// # }
+/// ```
+pub mod bounds_as_string {
+    use std::fmt;
+    use std::str::FromStr;
+
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use bounds::Bounds;
+
+    /// Serialises the range as a string.
+    pub fn serialize<T, S>(bounds: &Bounds<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where T: fmt::Debug,
+          S: Serializer,
+    {
+        serializer.collect_str(bounds)
+    }
+
+    /// Deserialises the range from a string.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Bounds<T>, D::Error>
+    where T: FromStr,
+          T::Err: fmt::Display,
+          D: Deserializer<'de>,
+    {
+        let text = String::deserialize(deserializer)?;
+        text.parse().map_err(D::Error::custom)
+    }
+}
@@ -0,0 +1,296 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::iter::FromIterator;
+use std::ops::Bound;
+use std::slice;
+
+use algebra::{cmp_lowers, cmp_uppers};
+use bounds::Bounds;
+use check::{ErrorKind, OutOfRangeError};
+use target::RangeTarget;
+
+
+/// A set of values made up of any number of ranges.
+///
+/// The ranges are kept sorted, and any that overlap or touch each other are
+/// merged together, so two sets containing the same values always contain
+/// the same ranges. Empty ranges are discarded.
+///
+/// A `RangeSet` can be used as the target of `check_range`, for when a value
+/// is allowed to be in one of several different ranges. Its `check` method
+/// does the same thing without needing the `Check` trait in scope.
+///
+/// # Examples
+///
+/// ```
+/// use range_check::{Bounds, Check, RangeSet};
+///
+/// let ports: RangeSet<u16> = vec![
+///     Bounds::half_open(1, 1024),
+///     Bounds::half_open(8000, 9000),
+/// ].into_iter().collect();
+///
+/// assert!(80.check_range(&ports).is_ok());
+/// assert!(8080.check_range(&ports).is_ok());
+///
+/// assert_eq!(5000.check_range(&ports).unwrap_err().to_string(),
+///            "value (5000) between ranges (1..1024, 8000..9000)");
+/// ```
+#[derive(PartialEq, Debug, Clone)]
+pub struct RangeSet<T> {
+    ranges: Vec<Bounds<T>>,
+}
+
+impl<T> RangeSet<T> {
+
+    /// Creates a new set with no values in it.
+    pub fn new() -> Self {
+        RangeSet { ranges: Vec::new() }
+    }
+
+    /// Returns an iterator over the ranges in this set, in ascending order.
+    pub fn iter(&self) -> slice::Iter<'_, Bounds<T>> {
+        self.ranges.iter()
+    }
+
+    /// Returns the ranges in this set as a slice, in ascending order.
+    pub fn as_slice(&self) -> &[Bounds<T>] {
+        &self.ranges
+    }
+
+    /// Returns whether this set has no values in it.
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+}
+
+impl<T: PartialOrd + Clone> RangeSet<T> {
+
+    /// Returns whether the given value lies within any of the ranges in this
+    /// set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_check::{Bounds, RangeSet};
+    ///
+    /// let set: RangeSet<i32> = vec![ Bounds::closed(1, 3), Bounds::closed(7, 9) ]
+    ///                              .into_iter().collect();
+    ///
+    /// assert!(set.contains(&2));
+    /// assert!(!set.contains(&5));
+    /// ```
+    pub fn contains(&self, value: &T) -> bool {
+        self.ranges.iter().any(|r| r.contains(value))
+    }
+
+    /// Checks whether the given value lies within any of the ranges in this
+    /// set. If it does, re-returns the value. Otherwise, returns an `Error`
+    /// that contains the value and every range in the set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_check::{Bounds, RangeSet, Violation};
+    ///
+    /// let set: RangeSet<i32> = vec![ Bounds::closed(1, 3), Bounds::closed(7, 9) ]
+    ///                              .into_iter().collect();
+    ///
+    /// assert_eq!(set.check(2), Ok(2));
+    /// assert_eq!(set.check(5).unwrap_err().violation(), Violation::InGap);
+    /// ```
+    pub fn check(&self, value: T) -> Result<T, OutOfRangeError<T>> {
+        if self.contains(&value) {
+            Ok(value)
+        }
+        else {
+            Err(self.error_for(value))
+        }
+    }
+
+    /// Creates the error for a value that lies outside every range in this
+    /// set.
+    pub(crate) fn error_for(&self, value: T) -> OutOfRangeError<T> {
+        // An empty set has no hull, so use an empty range at the value.
+        let allowed_range = self.hull().unwrap_or_else(|| Bounds::open(value.clone(), value.clone()));
+        let kind = if value.partial_cmp(&value).is_some() { ErrorKind::OutsideSet(self.ranges.clone()) } else { ErrorKind::Incomparable };
+        OutOfRangeError::new(allowed_range, value, kind)
+    }
+
+    /// Adds the values in a range to this set, merging it with any ranges
+    /// that it overlaps or touches.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_check::{Bounds, RangeSet};
+    ///
+    /// let mut set = RangeSet::new();
+    /// set.insert(Bounds::half_open(1, 5));
+    /// set.insert(Bounds::half_open(5, 9));
+    ///
+    /// assert_eq!(set.as_slice(), &[ Bounds::half_open(1, 9) ]);
+    /// ```
+    pub fn insert(&mut self, range: Bounds<T>) {
+        self.ranges.push(range);
+        self.normalise();
+    }
+
+    /// Removes the values in a range from this set, shortening or splitting
+    /// any ranges that it overlaps.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_check::{Bounds, RangeSet};
+    ///
+    /// let mut set = RangeSet::new();
+    /// set.insert(Bounds::closed(1, 9));
+    /// set.remove(&Bounds::open(3, 6));
+    ///
+    /// assert_eq!(set.as_slice(), &[ Bounds::closed(1, 3), Bounds::closed(6, 9) ]);
+    /// ```
+    pub fn remove(&mut self, range: &Bounds<T>) {
+        self.ranges = self.ranges.iter()
+                          .flat_map(|r| r.difference(range))
+                          .collect();
+    }
+
+    /// Returns a new set containing the values in either this set or the
+    /// other set.
+    pub fn union(&self, other: &Self) -> Self {
+        self.ranges.iter().chain(other.ranges.iter()).cloned().collect()
+    }
+
+    /// Returns a new set containing the values in both this set and the
+    /// other set.
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.ranges.iter()
+            .flat_map(|a| other.ranges.iter().filter_map(move |b| a.intersection(b)))
+            .collect()
+    }
+
+    /// Returns a new set containing the values in this set that are not in
+    /// the other set.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut set = self.clone();
+        for range in &other.ranges {
+            set.remove(range);
+        }
+        set
+    }
+
+    /// Returns the smallest range that covers every range in this set, or
+    /// `None` if the set is empty.
+    pub fn hull(&self) -> Option<Bounds<T>> {
+        match (self.ranges.first(), self.ranges.last()) {
+            (Some(first), Some(last))  => Some(first.hull(last)),
+            _                          => None,
+        }
+    }
+
+    // Sorts the ranges, discards any empty ones, and merges any that overlap
+    // or touch, so that the ranges are always disjoint.
+    fn normalise(&mut self) {
+        let mut ranges = Vec::new();
+        ::std::mem::swap(&mut ranges, &mut self.ranges);
+
+        ranges.retain(|r| ! r.is_empty());
+        ranges.sort_by(|a, b| cmp_lowers(&a.lower, &b.lower));
+
+        for range in ranges {
+            if let Some(last) = self.ranges.last_mut() {
+                if touches(last, &range) {
+                    if cmp_uppers(&range.upper, &last.upper) == Ordering::Greater {
+                        last.upper = range.upper;
+                    }
+                    continue;
+                }
+            }
+
+            self.ranges.push(range);
+        }
+    }
+}
+
+impl<T> Default for RangeSet<T> {
+    fn default() -> Self {
+        RangeSet::new()
+    }
+}
+
+impl<T: PartialOrd + Clone> FromIterator<Bounds<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item=Bounds<T>>>(iter: I) -> Self {
+        let mut set = RangeSet { ranges: iter.into_iter().collect() };
+        set.normalise();
+        set
+    }
+}
+
+impl<T: PartialOrd + Clone> Extend<Bounds<T>> for RangeSet<T> {
+    fn extend<I: IntoIterator<Item=Bounds<T>>>(&mut self, iter: I) {
+        self.ranges.extend(iter);
+        self.normalise();
+    }
+}
+
+impl<'a, T> IntoIterator for &'a RangeSet<T> {
+    type Item = &'a Bounds<T>;
+    type IntoIter = slice::Iter<'a, Bounds<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.ranges.iter()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Display for RangeSet<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_ranges(f, &self.ranges, &|f, range| write!(f, "{}", range))
+    }
+}
+
+pub(crate) fn write_ranges<T>(f: &mut fmt::Formatter, ranges: &[Bounds<T>], write_range: &dyn Fn(&mut fmt::Formatter, &Bounds<T>) -> fmt::Result) -> fmt::Result {
+    for (i, range) in ranges.iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+
+        write_range(f, range)?;
+    }
+
+    Ok(())
+}
+
+/// Whether the second range starts before the first range ends, or exactly
+/// where it ends, meaning the two can be merged. The first range must not
+/// start after the second.
+fn touches<T: PartialOrd>(first: &Bounds<T>, second: &Bounds<T>) -> bool {
+    match (&first.upper, &second.lower) {
+        (Bound::Unbounded, _) | (_, Bound::Unbounded)  => true,
+        (Bound::Excluded(u), Bound::Excluded(l))        => l < u,
+        (Bound::Included(u), Bound::Included(l)) |
+        (Bound::Included(u), Bound::Excluded(l)) |
+        (Bound::Excluded(u), Bound::Included(l))        => l <= u,
+    }
+}
+
+
+impl<T: PartialOrd + Clone> RangeTarget<T> for RangeSet<T> {
+    fn contains_value(&self, value: &T) -> bool {
+        self.contains(value)
+    }
+
+    fn into_error(self, value: T) -> OutOfRangeError<T> {
+        self.error_for(value)
+    }
+}
+
+impl<T: PartialOrd + Clone> RangeTarget<T> for &RangeSet<T> {
+    fn contains_value(&self, value: &T) -> bool {
+        self.contains(value)
+    }
+
+    fn into_error(self, value: T) -> OutOfRangeError<T> {
+        self.error_for(value)
+    }
+}
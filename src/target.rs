@@ -0,0 +1,87 @@
+use std::ops::{Bound, RangeBounds};
+use std::ops::{Range, RangeInclusive, RangeFrom, RangeTo, RangeToInclusive, RangeFull};
+
+use bounds::{Bounds, copy_bound};
+use check::{ErrorKind, OutOfRangeError};
+
+
+/// Something that a value can be checked against with `check_range`.
+///
+/// This is implemented for all of Rust’s standard `Range` types, for
+/// `Bounds`, and for `RangeSet`. To check values against a range type of your
+/// own, implement this trait for it.
+///
+/// # Examples
+///
+/// ```
+/// use range_check::{Bounds, Check, ErrorKind, OutOfRangeError, RangeTarget};
+///
+/// /// Any even number.
+/// struct Evens;
+///
+/// impl RangeTarget<u32> for Evens {
+///     fn contains_value(&self, value: &u32) -> bool {
+///         value % 2 == 0
+///     }
+///
+///     fn into_error(self, value: u32) -> OutOfRangeError<u32> {
+///         OutOfRangeError::new(Bounds::unbounded(), value, ErrorKind::Outside)
+///     }
+/// }
+///
+/// assert!(4.check_range(Evens).is_ok());
+/// assert!(5.check_range(Evens).is_err());
+/// ```
+pub trait RangeTarget<T> {
+
+    /// Returns whether the given value lies within this range.
+    fn contains_value(&self, value: &T) -> bool;
+
+    /// Turns this range into the error returned when the given value does
+    /// not lie within it.
+    fn into_error(self, value: T) -> OutOfRangeError<T>;
+}
+
+
+// Any standard RangeBounds type turns into a single Bounds value.
+macro_rules! impl_range_target {
+    ($($ty:ty),*) => {
+        $(
+            impl<'a, T: PartialOrd + Copy> RangeTarget<T> for $ty {
+                fn contains_value(&self, value: &T) -> bool {
+                    RangeBounds::contains(self, value)
+                }
+
+                fn into_error(self, value: T) -> OutOfRangeError<T> {
+                    let bounds = Bounds {
+                        lower: copy_bound(self.start_bound()),
+                        upper: copy_bound(self.end_bound()),
+                    };
+
+                    let kind = ErrorKind::of_failed_check(&bounds, &value);
+                    OutOfRangeError::new(bounds, value, kind)
+                }
+            }
+        )*
+    };
+}
+
+impl_range_target! {
+    Range<T>, RangeInclusive<T>, RangeFrom<T>, RangeTo<T>, RangeToInclusive<T>,
+    (Bound<T>, Bound<T>), Bounds<T>, &'a Bounds<T>,
+
+    Range<&'a T>, RangeInclusive<&'a T>, RangeFrom<&'a T>, RangeTo<&'a T>, RangeToInclusive<&'a T>,
+    (Bound<&'a T>, Bound<&'a T>)
+}
+
+impl<T: PartialOrd + Copy> RangeTarget<T> for RangeFull {
+    fn contains_value(&self, _value: &T) -> bool {
+        true
+    }
+
+    fn into_error(self, value: T) -> OutOfRangeError<T> {
+        // Never reached through check_range, as every value lies within a
+        // full range.
+        OutOfRangeError::new(Bounds::unbounded(), value, ErrorKind::Outside)
+    }
+}
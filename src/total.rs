@@ -0,0 +1,96 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+
+/// A float that is compared using the IEEE 754 `totalOrder` predicate,
+/// rather than the usual partial order, so that every float can be checked
+/// against a range.
+///
+/// In this order, negative NaNs sort below negative infinity, positive NaNs
+/// sort above positive infinity, and negative zero sorts just below positive
+/// zero. This means NaN is reported as lying above (or below) a range rather
+/// than being incomparable, and `-0.0` lies outside of `0.0 ..`.
+///
+/// # Examples
+///
+/// ```
+/// use range_check::{Check, TotalOrder, Violation};
+///
+/// let range = TotalOrder(0.0) .. TotalOrder(1.0);
+///
+/// assert!(TotalOrder(0.5).check_range(range.clone()).is_ok());
+///
+/// let err = TotalOrder(f64::NAN).check_range(range.clone()).unwrap_err();
+/// assert_eq!(err.violation(), Violation::AboveUpper);
+///
+/// let err = TotalOrder(-0.0).check_range(range).unwrap_err();
+/// assert_eq!(err.violation(), Violation::BelowLower);
+/// ```
+#[derive(Copy, Clone, Default)]
+pub struct TotalOrder<F>(pub F);
+
+impl<F> TotalOrder<F> {
+
+    /// Returns the float inside this wrapper.
+    pub fn into_inner(self) -> F {
+        self.0
+    }
+}
+
+macro_rules! impl_total_order {
+    ($($t:ty),*) => {
+        $(
+            impl PartialEq for TotalOrder<$t> {
+                fn eq(&self, other: &Self) -> bool {
+                    self.cmp(other) == Ordering::Equal
+                }
+            }
+
+            impl Eq for TotalOrder<$t> {}
+
+            impl PartialOrd for TotalOrder<$t> {
+                fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                    Some(self.cmp(other))
+                }
+            }
+
+            impl Ord for TotalOrder<$t> {
+                fn cmp(&self, other: &Self) -> Ordering {
+                    self.0.total_cmp(&other.0)
+                }
+            }
+
+            // Two floats are equal in the total order exactly when their bits
+            // are, so hashing the bits is consistent with Eq.
+            impl Hash for TotalOrder<$t> {
+                fn hash<H: Hasher>(&self, state: &mut H) {
+                    self.0.to_bits().hash(state);
+                }
+            }
+
+            impl From<$t> for TotalOrder<$t> {
+                fn from(float: $t) -> Self {
+                    TotalOrder(float)
+                }
+            }
+        )*
+    };
+}
+
+impl_total_order! { f32, f64 }
+
+// The wrapper is left out of formatted output, so that error messages show
+// the float on its own.
+
+impl<F: fmt::Debug> fmt::Debug for TotalOrder<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<F: fmt::Display> fmt::Display for TotalOrder<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
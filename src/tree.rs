@@ -0,0 +1,345 @@
+use std::cmp::{self, Ordering};
+use std::fmt;
+use std::iter;
+use std::ops::Bound;
+
+use algebra::{cmp_lowers, cmp_uppers, ends_before, lower_admits, upper_admits};
+use bounds::Bounds;
+
+
+/// A collection of possibly-overlapping ranges, each with a value, that can
+/// be searched for the ranges containing a value or overlapping a range.
+///
+/// This is an augmented interval tree: a balanced binary tree sorted by the
+/// lower bounds of its ranges, where each node also stores the highest upper
+/// bound anywhere beneath it. This lets searches skip entire subtrees that
+/// end before the value being searched for, so inserting, removing, and
+/// searching all take logarithmic time (plus the number of ranges found).
+///
+/// The same range can be added more than once.
+///
+/// # Examples
+///
+/// ```
+/// use range_check::{Bounds, IntervalTree};
+///
+/// let mut bookings = IntervalTree::new();
+/// bookings.insert(Bounds::half_open(9, 12), "Alice");
+/// bookings.insert(Bounds::half_open(11, 14), "Bob");
+/// bookings.insert(Bounds::half_open(15, 17), "Carol");
+///
+/// let at_eleven: Vec<_> = bookings.containing(&11).into_iter().map(|e| *e.1).collect();
+/// assert_eq!(at_eleven, vec![ "Alice", "Bob" ]);
+///
+/// let afternoon: Vec<_> = bookings.overlapping(&Bounds::half_open(13, 18)).into_iter().map(|e| *e.1).collect();
+/// assert_eq!(afternoon, vec![ "Bob", "Carol" ]);
+/// ```
+#[derive(Clone)]
+pub struct IntervalTree<T, V> {
+    root: Link<T, V>,
+    len: usize,
+}
+
+type Link<T, V> = Option<Box<Node<T, V>>>;
+
+#[derive(Clone)]
+struct Node<T, V> {
+    range: Bounds<T>,
+    value: V,
+
+    /// The highest upper bound of any range in this subtree.
+    max_upper: Bound<T>,
+
+    /// The number of levels in this subtree, for keeping it balanced.
+    height: usize,
+
+    left: Link<T, V>,
+    right: Link<T, V>,
+}
+
+impl<T, V> IntervalTree<T, V> {
+
+    /// Creates a new tree with no ranges in it.
+    pub fn new() -> Self {
+        IntervalTree { root: None, len: 0 }
+    }
+
+    /// Returns the number of ranges in this tree.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether this tree has no ranges in it.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns an iterator over the ranges in this tree and their values, in
+    /// ascending order of range.
+    pub fn iter(&self) -> impl Iterator<Item=(&Bounds<T>, &V)> {
+        let mut stack = Vec::new();
+        push_left_spine(&mut stack, &self.root);
+
+        iter::from_fn(move || {
+            let node = stack.pop()?;
+            push_left_spine(&mut stack, &node.right);
+            Some((&node.range, &node.value))
+        })
+    }
+}
+
+impl<T: PartialOrd + Clone, V> IntervalTree<T, V> {
+
+    /// Adds a range to this tree with the given value.
+    pub fn insert(&mut self, range: Bounds<T>, value: V) {
+        let root = self.root.take();
+        self.root = Some(insert(root, range, value));
+        self.len += 1;
+    }
+
+    /// Removes one copy of the given range from this tree, returning its
+    /// value, or `None` if that exact range is not in the tree.
+    pub fn remove(&mut self, range: &Bounds<T>) -> Option<V> {
+        let mut removed = None;
+        let root = self.root.take();
+        self.root = remove(root, range, &mut removed);
+
+        if removed.is_some() {
+            self.len -= 1;
+        }
+
+        removed
+    }
+
+    /// Returns every range in this tree that contains the given value, along
+    /// with their values, in ascending order of range.
+    pub fn containing(&self, value: &T) -> Vec<(&Bounds<T>, &V)> {
+        let mut found = Vec::new();
+        collect_containing(&self.root, value, &mut found);
+        found
+    }
+
+    /// Returns every range in this tree that overlaps the given range, along
+    /// with their values, in ascending order of range.
+    pub fn overlapping(&self, range: &Bounds<T>) -> Vec<(&Bounds<T>, &V)> {
+        let mut found = Vec::new();
+        if ! range.is_empty() {
+            collect_overlapping(&self.root, range, &mut found);
+        }
+        found
+    }
+}
+
+impl<T, V> Default for IntervalTree<T, V> {
+    fn default() -> Self {
+        IntervalTree::new()
+    }
+}
+
+impl<T: fmt::Debug, V: fmt::Debug> fmt::Debug for IntervalTree<T, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+
+// Searching
+
+fn push_left_spine<'a, T, V>(stack: &mut Vec<&'a Node<T, V>>, mut link: &'a Link<T, V>) {
+    while let Some(node) = link {
+        stack.push(node);
+        link = &node.left;
+    }
+}
+
+fn collect_containing<'a, T: PartialOrd, V>(link: &'a Link<T, V>, value: &T, found: &mut Vec<(&'a Bounds<T>, &'a V)>) {
+    let node = match link {
+        Some(node)  => node,
+        None        => return,
+    };
+
+    // Every range in this subtree ends before the value.
+    if ! upper_admits(&node.max_upper, value) {
+        return;
+    }
+
+    collect_containing(&node.left, value, found);
+
+    // This range, and every range to its right, starts after the value.
+    if ! lower_admits(&node.range.lower, value) {
+        return;
+    }
+
+    if upper_admits(&node.range.upper, value) {
+        found.push((&node.range, &node.value));
+    }
+
+    collect_containing(&node.right, value, found);
+}
+
+fn collect_overlapping<'a, T: PartialOrd + Clone, V>(link: &'a Link<T, V>, range: &Bounds<T>, found: &mut Vec<(&'a Bounds<T>, &'a V)>) {
+    let node = match link {
+        Some(node)  => node,
+        None        => return,
+    };
+
+    // Every range in this subtree ends before the range starts.
+    if ends_before(&node.max_upper, &range.lower) {
+        return;
+    }
+
+    collect_overlapping(&node.left, range, found);
+
+    // This range, and every range to its right, starts after the range ends.
+    if ends_before(&range.upper, &node.range.lower) {
+        return;
+    }
+
+    if node.range.overlaps(range) {
+        found.push((&node.range, &node.value));
+    }
+
+    collect_overlapping(&node.right, range, found);
+}
+
+
+// Modifying
+
+fn cmp_ranges<T: PartialOrd>(a: &Bounds<T>, b: &Bounds<T>) -> Ordering {
+    cmp_lowers(&a.lower, &b.lower).then_with(|| cmp_uppers(&a.upper, &b.upper))
+}
+
+fn insert<T: PartialOrd + Clone, V>(link: Link<T, V>, range: Bounds<T>, value: V) -> Box<Node<T, V>> {
+    let mut node = match link {
+        Some(node) => node,
+        None => {
+            let max_upper = range.upper.clone();
+            return Box::new(Node { range, value, max_upper, height: 1, left: None, right: None });
+        }
+    };
+
+    if cmp_ranges(&range, &node.range) == Ordering::Less {
+        node.left = Some(insert(node.left.take(), range, value));
+    }
+    else {
+        node.right = Some(insert(node.right.take(), range, value));
+    }
+
+    rebalance(node)
+}
+
+fn remove<T: PartialOrd + Clone, V>(link: Link<T, V>, range: &Bounds<T>, removed: &mut Option<V>) -> Link<T, V> {
+    let mut node = link?;
+
+    match cmp_ranges(range, &node.range) {
+        Ordering::Less => {
+            node.left = remove(node.left.take(), range, removed);
+        }
+        Ordering::Greater => {
+            node.right = remove(node.right.take(), range, removed);
+        }
+        Ordering::Equal if node.range == *range => {
+            let node = *node;
+            *removed = Some(node.value);
+
+            return match (node.left, node.right) {
+                (None, child) | (child, None) => child,
+                (left, Some(right)) => {
+                    let (mut successor, right) = remove_first(right);
+                    successor.left = left;
+                    successor.right = right;
+                    Some(rebalance(successor))
+                }
+            };
+        }
+        Ordering::Equal => {
+            // Ranges that compare equal can end up on either side.
+            node.left = remove(node.left.take(), range, removed);
+            if removed.is_none() {
+                node.right = remove(node.right.take(), range, removed);
+            }
+        }
+    }
+
+    Some(rebalance(node))
+}
+
+/// Detaches the leftmost node in a subtree, returning it along with the rest
+/// of the subtree.
+fn remove_first<T: PartialOrd + Clone, V>(mut node: Box<Node<T, V>>) -> (Box<Node<T, V>>, Link<T, V>) {
+    match node.left.take() {
+        None => {
+            let right = node.right.take();
+            (node, right)
+        }
+        Some(left) => {
+            let (first, left) = remove_first(left);
+            node.left = left;
+            (first, Some(rebalance(node)))
+        }
+    }
+}
+
+
+// Balancing
+
+fn height<T, V>(link: &Link<T, V>) -> usize {
+    link.as_ref().map_or(0, |n| n.height)
+}
+
+/// Recalculates a node’s height and highest upper bound from its children.
+fn update<T: PartialOrd + Clone, V>(node: &mut Node<T, V>) {
+    node.height = 1 + cmp::max(height(&node.left), height(&node.right));
+
+    let mut max_upper = &node.range.upper;
+    for child in node.left.iter().chain(node.right.iter()) {
+        if cmp_uppers(&child.max_upper, max_upper) == Ordering::Greater {
+            max_upper = &child.max_upper;
+        }
+    }
+
+    node.max_upper = max_upper.clone();
+}
+
+fn rotate_left<T: PartialOrd + Clone, V>(mut node: Box<Node<T, V>>) -> Box<Node<T, V>> {
+    let mut right = node.right.take().expect("rotating left without a right child");
+    node.right = right.left.take();
+    update(&mut node);
+    right.left = Some(node);
+    update(&mut right);
+    right
+}
+
+fn rotate_right<T: PartialOrd + Clone, V>(mut node: Box<Node<T, V>>) -> Box<Node<T, V>> {
+    let mut left = node.left.take().expect("rotating right without a left child");
+    node.left = left.right.take();
+    update(&mut node);
+    left.right = Some(node);
+    update(&mut left);
+    left
+}
+
+/// Restores the AVL property at this node, where the heights of its two
+/// subtrees differ by at most one.
+fn rebalance<T: PartialOrd + Clone, V>(mut node: Box<Node<T, V>>) -> Box<Node<T, V>> {
+    update(&mut node);
+
+    let left = height(&node.left);
+    let right = height(&node.right);
+
+    if left > right + 1 {
+        if let Some(child) = node.left.take() {
+            node.left = Some(if height(&child.left) < height(&child.right) { rotate_left(child) } else { child });
+        }
+        rotate_right(node)
+    }
+    else if right > left + 1 {
+        if let Some(child) = node.right.take() {
+            node.right = Some(if height(&child.right) < height(&child.left) { rotate_right(child) } else { child });
+        }
+        rotate_left(node)
+    }
+    else {
+        node
+    }
+}
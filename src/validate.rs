@@ -0,0 +1,215 @@
+use std::error::Error as ErrorTrait;
+use std::fmt;
+use std::mem;
+use std::slice;
+
+use check::Check;
+use field::{FieldError, join_path};
+
+
+/// A collector that runs many range checks and keeps every one that fails,
+/// rather than stopping at the first.
+///
+/// Each check is given the name of the field being checked. Checks made
+/// inside `nested` have the outer field’s name prefixed to their own, so the
+/// report ends up holding paths such as `clock.alarms[2].minute`.
+///
+/// Like `OutOfRangeError::generify`, values of any type that can be converted
+/// into `T` using `From` can be checked by the same validator.
+///
+/// # Examples
+///
+/// ```
+/// use range_check::Validator;
+///
+/// let mut validator = Validator::<i16>::new();
+/// validator.check("hour", 24_i8, 0..24)
+///          .check("minute", 60_i8, 0..60)
+///          .check("millisecond", 999_i16, 0..1000);
+///
+/// let report = validator.finish().unwrap_err();
+/// assert_eq!(report.len(), 2);
+/// assert_eq!(report.to_string(),
+///            "2 values out of range: hour: value (24) above range (0..24); minute: value (60) above range (0..60)");
+/// ```
+#[derive(PartialEq, Debug, Clone)]
+pub struct Validator<T> {
+    prefix: String,
+    failures: Vec<FieldError<T>>,
+}
+
+impl<T> Validator<T> {
+
+    /// Creates a new validator with no failed checks.
+    pub fn new() -> Self {
+        Validator { prefix: String::new(), failures: Vec::new() }
+    }
+
+    /// Checks that a value is within the given range, recording an error
+    /// under the given field name if it is not.
+    pub fn check<V, R>(&mut self, field: &str, value: V, range: R) -> &mut Self
+    where V: Check<R>,
+          T: From<V>,
+    {
+        if let Err(e) = value.check_range(range) {
+            let path = self.path_to(field);
+            self.failures.push(FieldError::new(&path, e.generify()));
+        }
+
+        self
+    }
+
+    /// Records a failed check made elsewhere, such as by the `validate`
+    /// method of a struct with `#[derive(RangeCheck)]`, under the given field
+    /// name. Any field name already in the error is treated as a path within
+    /// this one.
+    pub fn record<V, U>(&mut self, field: &str, result: Result<V, FieldError<U>>) -> &mut Self
+    where T: From<U>,
+    {
+        if let Err(e) = result {
+            let path = self.path_to(field);
+            self.failures.push(FieldError::new(&path, e.error.generify()));
+        }
+
+        self
+    }
+
+    /// Runs more checks with the given field name prefixed to the names of
+    /// every field they check, for validating values nested inside others.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_check::Validator;
+    ///
+    /// let alarms = [ (7, 30), (25, 0) ];
+    ///
+    /// let mut validator = Validator::<i32>::new();
+    /// for (index, (hour, minute)) in alarms.iter().enumerate() {
+    ///     validator.nested(&format!("alarms[{}]", index), |v| {
+    ///         v.check("hour", *hour, 0..24)
+    ///          .check("minute", *minute, 0..60);
+    ///     });
+    /// }
+    ///
+    /// let report = validator.finish().unwrap_err();
+    /// assert_eq!(report.iter().next().unwrap().field(), "alarms[1].hour");
+    /// ```
+    pub fn nested<F>(&mut self, field: &str, checks: F) -> &mut Self
+    where F: FnOnce(&mut Self)
+    {
+        let path = self.path_to(field);
+        let outer = mem::replace(&mut self.prefix, path);
+        checks(self);
+        self.prefix = outer;
+        self
+    }
+
+    /// Returns whether every check so far has passed.
+    pub fn is_valid(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    /// Finishes validating, returning a report of every failed check if
+    /// there were any.
+    pub fn finish(self) -> Result<(), ValidationReport<T>> {
+        if self.failures.is_empty() {
+            Ok(())
+        }
+        else {
+            Err(ValidationReport { failures: self.failures })
+        }
+    }
+
+    fn path_to(&self, field: &str) -> String {
+        join_path(&self.prefix, field)
+    }
+}
+
+impl<T> Default for Validator<T> {
+    fn default() -> Self {
+        Validator::new()
+    }
+}
+
+
+/// The error returned by a `Validator` when one or more checks failed,
+/// holding every failure in the order the checks were made.
+#[derive(PartialEq, Debug, Clone)]
+pub struct ValidationReport<T> {
+    failures: Vec<FieldError<T>>,
+}
+
+impl<T> ValidationReport<T> {
+
+    /// Returns an iterator over the failed checks.
+    pub fn iter(&self) -> slice::Iter<'_, FieldError<T>> {
+        self.failures.iter()
+    }
+
+    /// Returns the number of failed checks. This is never zero.
+    pub fn len(&self) -> usize {
+        self.failures.len()
+    }
+
+    /// Returns whether there are no failed checks, which is never the case
+    /// for a report returned by a `Validator`.
+    pub fn is_empty(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    /// Returns the failed checks.
+    pub fn into_failures(self) -> Vec<FieldError<T>> {
+        self.failures
+    }
+}
+
+impl<T> From<FieldError<T>> for ValidationReport<T> {
+    fn from(failure: FieldError<T>) -> Self {
+        ValidationReport { failures: vec![ failure ] }
+    }
+}
+
+impl<T> IntoIterator for ValidationReport<T> {
+    type Item = FieldError<T>;
+    type IntoIter = ::std::vec::IntoIter<FieldError<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.failures.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a ValidationReport<T> {
+    type Item = &'a FieldError<T>;
+    type IntoIter = slice::Iter<'a, FieldError<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.failures.iter()
+    }
+}
+
+impl<T: fmt::Debug + PartialOrd> fmt::Display for ValidationReport<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let [ failure ] = &self.failures[..] {
+            return write!(f, "{}", failure);
+        }
+
+        write!(f, "{} values out of range: ", self.failures.len())?;
+
+        for (i, failure) in self.failures.iter().enumerate() {
+            if i > 0 {
+                write!(f, "; ")?;
+            }
+
+            write!(f, "{}", failure)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: fmt::Debug + PartialOrd> ErrorTrait for ValidationReport<T> {
+    fn description(&self) -> &str {
+        "values outside of ranges"
+    }
+}
@@ -0,0 +1,83 @@
+extern crate range_check;
+use range_check::{AnyOutOfRangeError, Bounds, Check, ErrorKind, RangeSet, Violation};
+
+use std::any::TypeId;
+use std::error::Error;
+
+
+fn erase<T>(result: Result<T, range_check::OutOfRangeError<T>>) -> AnyOutOfRangeError
+where T: std::fmt::Debug + PartialOrd + 'static
+{
+    AnyOutOfRangeError::from(result.err().unwrap())
+}
+
+#[test]
+fn keeps_type() {
+    let err = erase(300_u64.check_range(0..256));
+    assert!(err.is::<u64>());
+    assert!(! err.is::<u32>());
+    assert_eq!(err.value_type_id(), TypeId::of::<u64>());
+    assert_eq!(err.type_name(), "u64");
+}
+
+#[test]
+fn keeps_rendered_values() {
+    let err = erase((-0.5_f32).check_range(0.0..=1.0));
+    assert_eq!(err.outside_value(), "-0.5");
+    assert_eq!(err.allowed_range(), "0.0..=1.0");
+    assert_eq!(err.violation(), Violation::BelowLower);
+}
+
+#[test]
+fn keeps_message() {
+    let original = 'z'.check_range('a'..'y').unwrap_err();
+    let message = original.to_string();
+    assert_eq!(AnyOutOfRangeError::from(original).to_string(), message);
+}
+
+#[test]
+fn keeps_path() {
+    let err = erase(61_u8.check_range_named("minute", 0..60));
+    assert_eq!(err.path(), Some("minute"));
+    assert_eq!(err.to_string(), "minute: value (61) above range (0..60)");
+}
+
+#[test]
+fn keeps_set_details() {
+    let set: RangeSet<i32> = vec![ Bounds::half_open(0, 10), Bounds::half_open(20, 30) ].into_iter().collect();
+    let err = erase(set.check(15));
+    assert_eq!(err.violation(), Violation::InGap);
+    assert_eq!(err.to_string(), "value (15) between ranges (0..10, 20..30)");
+
+    let ranges = vec![ Bounds::half_open("0".to_string(), "10".to_string()),
+                       Bounds::half_open("20".to_string(), "30".to_string()) ];
+    assert_eq!(err.kind(), &ErrorKind::OutsideSet(ranges));
+}
+
+#[test]
+fn keeps_kind() {
+    assert_eq!(erase(5.check_range(0..5)).kind(), &ErrorKind::Outside);
+    assert_eq!(erase(5.check_range(5..5)).kind(), &ErrorKind::EmptyRange);
+    assert_eq!(erase(f64::NAN.check_range(0.0..1.0)).kind(), &ErrorKind::Incomparable);
+}
+
+#[test]
+fn question_mark_across_types() {
+    fn check_all(a: i8, b: f64, c: char) -> Result<(), AnyOutOfRangeError> {
+        let _ = a.check_range(0..10)?;
+        let _ = b.check_range(0.0..10.0)?;
+        let _ = c.check_range('a'..='z')?;
+        Ok(())
+    }
+
+    assert!(check_all(1, 1.0, 'a').is_ok());
+    assert!(check_all(10, 1.0, 'a').unwrap_err().is::<i8>());
+    assert!(check_all(1, f64::NAN, 'a').unwrap_err().is::<f64>());
+    assert!(check_all(1, 1.0, 'A').unwrap_err().is::<char>());
+}
+
+#[test]
+fn boxes_as_error() {
+    let boxed: Box<dyn Error> = Box::new(erase(5.check_range(0..5)));
+    assert_eq!(boxed.to_string(), "value (5) above range (0..5)");
+}
@@ -0,0 +1,98 @@
+#[macro_use]
+extern crate range_check;
+
+use std::panic;
+
+
+fn panic_message<F: FnOnce() + panic::UnwindSafe>(f: F) -> String {
+    let payload = panic::catch_unwind(f).unwrap_err();
+    match payload.downcast::<String>() {
+        Ok(message)  => *message,
+        Err(_)       => panic!("panic payload was not a String"),
+    }
+}
+
+
+#[test]
+fn passes() {
+    let hour = 23;
+    assert_in_range!(hour, 0..24);
+    assert_in_range!(hour, 0..24,);
+    assert_in_range!(hour, 0..24, "hour {} is fine", hour);
+}
+
+#[test]
+fn fails_above() {
+    let message = panic_message(|| assert_in_range!(24, 0..24));
+    assert_eq!(message, "assertion failed: `24` in range: value (24) above range (0..24)");
+}
+
+#[test]
+fn fails_below_with_expression() {
+    let hour = 3;
+    let message = panic_message(|| assert_in_range!(hour - 4, 0..24));
+    assert_eq!(message, "assertion failed: `hour - 4` in range: value (-1) below range (0..24)");
+}
+
+#[test]
+fn fails_with_message() {
+    let message = panic_message(|| assert_in_range!(61, 0..=59, "bad minute in {}", "alarm"));
+    assert_eq!(message, "assertion failed: `61` in range: value (61) above range (0..=59): bad minute in alarm");
+}
+
+#[test]
+fn fails_with_empty_range() {
+    let message = panic_message(|| assert_in_range!(5, 5..5));
+    assert_eq!(message, "assertion failed: `5` in range: range (5..5) is empty, so cannot contain value (5)");
+}
+
+#[test]
+fn non_copy_values() {
+    let name = String::from("Marmoset");
+    assert_in_range!(name, String::from("A") .. String::from("N"));
+    assert_eq!(name, "Marmoset");
+}
+
+#[test]
+fn evaluates_once() {
+    let mut calls = 0;
+    assert_in_range!({ calls += 1; calls }, 0..10);
+    assert_eq!(calls, 1);
+}
+
+thread_local! {
+    static LOCATION: std::cell::RefCell<Option<(String, u32)>> = const { std::cell::RefCell::new(None) };
+}
+
+#[test]
+fn reports_call_site() {
+    // Other tests panic on their own threads, so the location is stored
+    // per-thread to avoid picking theirs up.
+    let previous = panic::take_hook();
+    panic::set_hook(Box::new(|info| {
+        let location = info.location().map(|l| (l.file().to_owned(), l.line()));
+        LOCATION.with(|l| *l.borrow_mut() = location);
+    }));
+
+    let line = line!() + 1;
+    let result = panic::catch_unwind(|| assert_in_range!(100, 0..10));
+    panic::set_hook(previous);
+
+    assert!(result.is_err());
+    assert_eq!(LOCATION.with(|l| l.borrow().clone()), Some((file!().to_owned(), line)));
+}
+
+#[test]
+#[cfg(debug_assertions)]
+fn debug_fails() {
+    let message = panic_message(|| debug_assert_in_range!(10, 0..10));
+    assert_eq!(message, "assertion failed: `10` in range: value (10) above range (0..10)");
+}
+
+#[test]
+#[cfg(not(debug_assertions))]
+fn debug_does_nothing() {
+    let mut calls = 0;
+    debug_assert_in_range!({ calls += 1; 100 }, 0..10);
+    assert_eq!(calls, 0);
+}
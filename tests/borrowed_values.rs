@@ -0,0 +1,95 @@
+extern crate range_check;
+use range_check::{Bounds, CheckRef, ErrorKind, OutOfRangeError, Violation};
+
+use std::ops::Bound;
+
+
+/// A number type that is deliberately not `Copy`.
+#[derive(PartialEq, PartialOrd, Debug, Clone)]
+struct BigNum(Vec<u32>);
+
+
+#[test]
+fn strings() {
+    let range = String::from("b") .. String::from("d");
+    assert!(String::from("c").check_range_ref(&range).is_ok());
+
+    let value = String::from("a");
+    let err = value.check_range_ref(&range).unwrap_err();
+    assert_eq!(err.outside_value, &value);
+    assert_eq!(err.violation(), Violation::BelowLower);
+}
+
+#[test]
+fn borrowed_str() {
+    let range = (Bound::Included("b"), Bound::Included("d"));
+    let owned = String::from("d");
+    assert_eq!(owned.as_str().check_range_ref(&range), Ok("d"));
+    assert!("e".check_range_ref(&range).is_err());
+}
+
+#[test]
+fn byte_vectors() {
+    let range = vec![ 1_u8, 0 ] ..= vec![ 1, 255 ];
+    assert!(vec![ 1_u8, 10 ].check_range_ref(&range).is_ok());
+    assert!(vec![ 2_u8 ].check_range_ref(&range).is_err());
+}
+
+#[test]
+fn byte_slices() {
+    let range = (Bound::Included(&b"aa"[..]), Bound::Unbounded);
+    assert!(b"ab"[..].check_range_ref(&range).is_ok());
+    assert!(b"a"[..].check_range_ref(&range).is_err());
+}
+
+#[test]
+fn non_copy_numbers() {
+    let range = Bounds::half_open(BigNum(vec![ 0 ]), BigNum(vec![ 1, 0 ]));
+    assert!(BigNum(vec![ 0, 5 ]).check_range_ref(&range).is_ok());
+
+    let value = BigNum(vec![ 2 ]);
+    let err = value.check_range_ref(&range).unwrap_err();
+    assert_eq!(err.violation(), Violation::AboveUpper);
+    assert_eq!(err.to_string(), "value (BigNum([2])) above range (BigNum([0])..BigNum([1, 0]))");
+}
+
+#[test]
+fn into_owned() {
+    let range = Bounds::half_open(BigNum(vec![ 0 ]), BigNum(vec![ 1 ]));
+    let owned: OutOfRangeError<BigNum> = BigNum(vec![ 3 ]).check_range_ref(&range).unwrap_err().into_owned();
+
+    assert_eq!(owned.outside_value, BigNum(vec![ 3 ]));
+    assert_eq!(owned.allowed_range, range);
+}
+
+#[test]
+fn str_into_owned() {
+    let range = (Bound::Excluded("m"), Bound::Unbounded);
+    let owned: OutOfRangeError<String> = "a".check_range_ref(&range).unwrap_err().into_owned();
+
+    assert_eq!(owned.outside_value, "a");
+    assert_eq!(owned.allowed_range.lower, Bound::Excluded(String::from("m")));
+}
+
+#[test]
+fn into_owned_keeps_path() {
+    let range = String::from("b") .. String::from("d");
+    let err = String::from("z").check_range_ref(&range).unwrap_err().with_path("name").into_owned();
+    assert_eq!(err.path.as_deref(), Some("name"));
+}
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn empty_ranges() {
+    let inverted = String::from("z") .. String::from("a");
+    assert_eq!(String::from("m").check_range_ref(&inverted).unwrap_err().kind, ErrorKind::InvertedRange);
+
+    let empty = String::from("m") .. String::from("m");
+    assert_eq!(String::from("m").check_range_ref(&empty).unwrap_err().kind, ErrorKind::EmptyRange);
+}
+
+#[test]
+fn copy_values_too() {
+    assert_eq!(f64::NAN.check_range_ref(&(0.0 .. 1.0)).unwrap_err().kind, ErrorKind::Incomparable);
+    assert_eq!(5.check_range_ref(&(0 .. 10)), Ok(&5));
+}
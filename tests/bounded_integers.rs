@@ -0,0 +1,136 @@
+extern crate range_check;
+use range_check::{Bounded, Bounds, ErrorKind, Violation};
+
+use std::collections::HashSet;
+use std::convert::{TryFrom, TryInto};
+
+
+type Hour = Bounded<u8, 0, 23>;
+type Offset = Bounded<i16, -12, 14>;
+type Percent = Bounded<u64, 0, 100>;
+
+
+#[test]
+fn construct() {
+    assert_eq!(*Hour::new(0).unwrap(), 0);
+    assert_eq!(*Hour::new(23).unwrap(), 23);
+    assert_eq!(Hour::new(24).unwrap_err().violation(), Violation::AboveUpper);
+    assert_eq!(Offset::new(-13).unwrap_err().violation(), Violation::BelowLower);
+}
+
+#[test]
+fn error_shows_range() {
+    assert_eq!(Hour::new(24).unwrap_err().to_string(),
+               "value (24) above range (0..=23)");
+}
+
+#[test]
+fn try_from() {
+    assert!(Hour::try_from(12).is_ok());
+    assert!(Hour::try_from(99).is_err());
+
+    let hour: Result<Hour, _> = 5_u8.try_into();
+    assert_eq!(hour.map(u8::from), Ok(5));
+}
+
+#[test]
+fn display_and_debug() {
+    let offset = Offset::new(-5).unwrap();
+    assert_eq!(offset.to_string(), "-5");
+    assert_eq!(format!("{:?}", offset), "-5");
+}
+
+#[test]
+fn ordering_and_hashing() {
+    let a = Hour::new(3).unwrap();
+    let b = Hour::new(7).unwrap();
+    assert!(a < b);
+    assert_eq!(a.max(b), b);
+
+    let set: HashSet<Hour> = vec![ a, b, a ].into_iter().collect();
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn deref() {
+    let percent = Percent::new(40).unwrap();
+    assert_eq!(percent.pow(2), 1600);
+    assert_eq!(percent.into_inner(), 40);
+}
+
+#[test]
+fn bounds_beyond_the_type() {
+    type Byte = Bounded<u8, -100, 1000>;
+    assert_eq!(Byte::range(), Bounds::closed(0, 255));
+    assert!(Byte::new(255).is_ok());
+}
+
+#[test]
+fn one_bound_beyond_the_type() {
+    type Top = Bounded<u8, 200, 1000>;
+    assert_eq!(Top::range(), Bounds::closed(200, 255));
+
+    type Bottom = Bounded<i8, -1000, -100>;
+    assert_eq!(Bottom::range(), Bounds::closed(-128, -100));
+}
+
+#[test]
+fn range_above_the_type() {
+    type Nothing = Bounded<u8, 300, 400>;
+    assert_eq!(Nothing::new(50).unwrap_err().kind, ErrorKind::EmptyRange);
+    assert!(Nothing::new(255).is_err());
+}
+
+#[test]
+fn range_below_the_type() {
+    type Nothing = Bounded<u8, -10, -5>;
+    assert_eq!(Nothing::new(200).unwrap_err().kind, ErrorKind::EmptyRange);
+    assert!(Nothing::new(0).is_err());
+}
+
+#[test]
+fn inverted_beyond_the_type() {
+    type Nothing = Bounded<u8, 300, 280>;
+    assert!(Nothing::new(255).is_err());
+}
+
+#[test]
+fn inverted_bounds() {
+    type Nothing = Bounded<u8, 10, 5>;
+    assert_eq!(Nothing::new(7).unwrap_err().kind, ErrorKind::InvertedRange);
+}
+
+
+#[test]
+fn checked() {
+    let hour = Hour::new(20).unwrap();
+    assert_eq!(hour.checked_add(3).map(u8::from), Some(23));
+    assert_eq!(hour.checked_add(4), None);
+    assert_eq!(hour.checked_sub(20).map(u8::from), Some(0));
+    assert_eq!(hour.checked_sub(21), None);
+}
+
+#[test]
+fn saturating() {
+    let offset = Offset::new(10).unwrap();
+    assert_eq!(*offset.saturating_add(100), 14);
+    assert_eq!(*offset.saturating_sub(100), -12);
+    assert_eq!(*offset.saturating_add(i16::MAX), 14);
+}
+
+#[test]
+fn wrapping() {
+    let hour = Hour::new(22).unwrap();
+    assert_eq!(*hour.wrapping_add(2), 0);
+    assert_eq!(*hour.wrapping_add(255), 13);
+    assert_eq!(*hour.wrapping_sub(23), 23);
+    assert_eq!(*Hour::new(0).unwrap().wrapping_sub(1), 23);
+}
+
+#[test]
+fn wrapping_full_width() {
+    type Full = Bounded<u64, 0, { u64::MAX as i128 }>;
+    let max = Full::new(u64::MAX).unwrap();
+    assert_eq!(*max.wrapping_add(1), 0);
+    assert_eq!(*max.saturating_add(1), u64::MAX);
+}
@@ -0,0 +1,210 @@
+extern crate range_check;
+use range_check::Bounds;
+
+use std::ops::Bound;
+use std::ops::Bound::*;
+
+
+// Every bound that can be made from the endpoints 1 to 3, so that every
+// combination of Included, Excluded, and Unbounded gets tested against every
+// other. Membership is sampled at whole and half values between them, so
+// excluded endpoints behave as they would for a continuous type.
+
+fn all_bounds() -> Vec<Bounds<f64>> {
+    let mut ends = vec![ Unbounded ];
+    for n in 1 ..= 3 {
+        ends.push(Included(n as f64));
+        ends.push(Excluded(n as f64));
+    }
+
+    let mut all = Vec::new();
+    for lower in &ends {
+        for upper in &ends {
+            all.push(Bounds { lower: *lower, upper: *upper });
+        }
+    }
+    all
+}
+
+fn samples() -> Vec<f64> {
+    (0 ..= 8).map(|n| n as f64 / 2.0).collect()
+}
+
+fn any_contains(pieces: &[Bounds<f64>], value: f64) -> bool {
+    pieces.iter().any(|p| p.contains(&value))
+}
+
+
+#[test]
+fn intersection_exhaustive() {
+    for a in all_bounds() {
+        for b in all_bounds() {
+            let both = a.intersection(&b);
+
+            for x in samples() {
+                let expected = a.contains(&x) && b.contains(&x);
+                let actual = both.iter().any(|r| r.contains(&x));
+                assert_eq!(expected, actual, "{} ∩ {} at {}", a, b, x);
+            }
+
+            assert_eq!(both, b.intersection(&a));
+        }
+    }
+}
+
+#[test]
+fn hull_exhaustive() {
+    for a in all_bounds() {
+        for b in all_bounds() {
+            let hull = a.hull(&b);
+
+            for x in samples() {
+                if a.contains(&x) || b.contains(&x) {
+                    assert!(hull.contains(&x), "{} hull {} at {}", a, b, x);
+                }
+            }
+
+            if a.is_empty() && b.is_empty() {
+                assert!(hull.is_empty(), "{} hull {}", a, b);
+            }
+            else if a.is_empty() {
+                assert_eq!(hull, b);
+            }
+            else if b.is_empty() {
+                assert_eq!(hull, a);
+            }
+            else {
+                assert_eq!(hull, b.hull(&a));
+            }
+        }
+    }
+}
+
+#[test]
+fn difference_exhaustive() {
+    for a in all_bounds() {
+        for b in all_bounds() {
+            let pieces = a.difference(&b);
+            assert!(pieces.len() <= 2);
+
+            for x in samples() {
+                let expected = a.contains(&x) && ! b.contains(&x);
+                assert_eq!(expected, any_contains(&pieces, x), "{} - {} at {}", a, b, x);
+            }
+        }
+    }
+}
+
+#[test]
+fn complement_exhaustive() {
+    for a in all_bounds() {
+        let pieces = a.complement();
+        assert!(pieces.len() <= 2);
+
+        for x in samples() {
+            assert_eq!(! a.contains(&x), any_contains(&pieces, x), "!{} at {}", a, x);
+        }
+    }
+}
+
+
+#[test]
+fn intersection_touching() {
+    assert_eq!(Bounds::closed(1, 3).intersection(&Bounds::closed(3, 5)),
+               Some(Bounds::closed(3, 3)));
+
+    assert_eq!(Bounds::half_open(1, 3).intersection(&Bounds::closed(3, 5)),
+               None);
+
+    assert_eq!(Bounds::closed(1, 3).intersection(&Bounds::open(3, 5)),
+               None);
+}
+
+#[test]
+fn intersection_prefers_excluded() {
+    assert_eq!(Bounds::closed(1, 5).intersection(&Bounds::open(1, 5)),
+               Some(Bounds::open(1, 5)));
+}
+
+#[test]
+fn intersection_unbounded() {
+    assert_eq!(Bounds::at_least(1).intersection(&Bounds::at_most(5)),
+               Some(Bounds::closed(1, 5)));
+
+    assert_eq!(Bounds::unbounded().intersection(&Bounds::half_open(1, 5)),
+               Some(Bounds::half_open(1, 5)));
+}
+
+#[test]
+fn hull_prefers_included() {
+    assert_eq!(Bounds::closed(1, 5).hull(&Bounds::open(1, 5)),
+               Bounds::closed(1, 5));
+}
+
+#[test]
+fn hull_fills_gap() {
+    assert_eq!(Bounds::open(1, 2).hull(&Bounds::open(4, 5)),
+               Bounds::open(1, 5));
+}
+
+#[test]
+fn hull_ignores_inverted() {
+    assert_eq!(Bounds::closed(0, 1).hull(&Bounds::closed(10, 5)),
+               Bounds::closed(0, 1));
+
+    assert_eq!(Bounds::closed(10, 5).hull(&Bounds::closed(0, 1)),
+               Bounds::closed(0, 1));
+}
+
+#[test]
+fn hull_ignores_empty() {
+    assert_eq!(Bounds::half_open(20, 20).hull(&Bounds::closed(0, 1)),
+               Bounds::closed(0, 1));
+}
+
+#[test]
+fn difference_split() {
+    assert_eq!(Bounds::closed(1, 9).difference(&Bounds::closed(4, 6)),
+               vec![ Bounds::half_open(1, 4),
+                     Bounds { lower: Excluded(6), upper: Included(9) } ]);
+}
+
+#[test]
+fn difference_disjoint() {
+    assert_eq!(Bounds::closed(1, 3).difference(&Bounds::closed(5, 9)),
+               vec![ Bounds::closed(1, 3) ]);
+}
+
+#[test]
+fn difference_covered() {
+    assert_eq!(Bounds::closed(4, 6).difference(&Bounds::closed(1, 9)),
+               vec![]);
+}
+
+#[test]
+fn complement_excluded() {
+    assert_eq!(Bounds::open(1, 5).complement(),
+               vec![ Bounds::at_most(1), Bounds::at_least(5) ]);
+}
+
+#[test]
+fn complement_of_empty() {
+    assert_eq!(Bounds::closed(5, 1).complement(),
+               vec![ Bounds::unbounded() ]);
+}
+
+#[test]
+fn complement_half() {
+    assert_eq!(Bounds::at_least(3).complement(),
+               vec![ Bounds { lower: Unbounded as Bound<i32>, upper: Excluded(3) } ]);
+}
+
+#[test]
+fn predicates_without_clone() {
+    #[derive(PartialEq, PartialOrd)]
+    struct Level(u8);
+
+    assert!(Bounds::half_open(Level(5), Level(5)).is_empty());
+    assert!(Bounds::closed(Level(5), Level(5)).is_singleton());
+    assert!(Bounds::closed(Level(5), Level(3)).is_inverted());
+}
@@ -0,0 +1,88 @@
+extern crate range_check;
+use range_check::{Bounds, Check};
+
+use std::ops::Bound;
+
+
+#[test]
+fn closed() {
+    let bounds = Bounds::closed(2, 4);
+    assert!(!bounds.contains(&1));
+    assert!( bounds.contains(&2));
+    assert!( bounds.contains(&4));
+    assert!(!bounds.contains(&5));
+}
+
+#[test]
+fn open() {
+    let bounds = Bounds::open(2, 4);
+    assert!(!bounds.contains(&2));
+    assert!( bounds.contains(&3));
+    assert!(!bounds.contains(&4));
+}
+
+#[test]
+fn half_open() {
+    let bounds = Bounds::half_open(2, 4);
+    assert!(!bounds.contains(&1));
+    assert!( bounds.contains(&2));
+    assert!( bounds.contains(&3));
+    assert!(!bounds.contains(&4));
+}
+
+#[test]
+fn at_least() {
+    let bounds = Bounds::at_least(2);
+    assert!(!bounds.contains(&1));
+    assert!( bounds.contains(&2));
+    assert!( bounds.contains(&i32::MAX));
+}
+
+#[test]
+fn at_most() {
+    let bounds = Bounds::at_most(2);
+    assert!( bounds.contains(&i32::MIN));
+    assert!( bounds.contains(&2));
+    assert!(!bounds.contains(&3));
+}
+
+#[test]
+fn unbounded() {
+    let bounds = Bounds::unbounded();
+    assert!(bounds.contains(&i32::MIN));
+    assert!(bounds.contains(&i32::MAX));
+}
+
+#[test]
+fn from_ranges() {
+    assert_eq!(Bounds::from_range_bounds(&(2 .. 4)),  Bounds::half_open(2, 4));
+    assert_eq!(Bounds::from_range_bounds(&(2 ..= 4)), Bounds::closed(2, 4));
+    assert_eq!(Bounds::from_range_bounds(&(2 ..)),    Bounds::at_least(2));
+    assert_eq!(Bounds::from_range_bounds(&(..= 4)),   Bounds::at_most(4));
+    assert_eq!(Bounds::<i32>::from_range_bounds(&(..)), Bounds::unbounded());
+    assert_eq!(Bounds::from_range_bounds(&(.. 4)),
+               Bounds { lower: Bound::Unbounded, upper: Bound::Excluded(4) });
+}
+
+#[test]
+fn from_non_copy() {
+    let bounds = Bounds::from_range_bounds(&(String::from("a") .. String::from("c")));
+    assert!( bounds.contains(&String::from("b")));
+    assert!(!bounds.contains(&String::from("c")));
+}
+
+#[test]
+fn handed_back_to_check() {
+    let err = 24680.check_range(1 .. 9999).unwrap_err();
+    assert_eq!(err.allowed_range, Bounds::half_open(1, 9999));
+
+    assert_eq!(1234.check_range(&err.allowed_range), Ok(1234));
+    assert!(9999.check_range(err.allowed_range).is_err());
+}
+
+#[test]
+fn same_error_when_stored() {
+    let stored = Bounds::closed(1, 5);
+    assert_eq!(6.check_range(stored.clone()).unwrap_err(),
+               6.check_range(1 ..= 5).unwrap_err());
+}
@@ -0,0 +1,184 @@
+extern crate range_check;
+use range_check::{Adjustment, Coerce, ErrorKind};
+
+use std::ops::Bound::*;
+
+
+// Clamping
+
+#[test]
+fn clamp_unchanged() {
+    let adjusted = 5.clamp_to_range(0 .. 10).unwrap();
+    assert_eq!(adjusted.value, 5);
+    assert_eq!(adjusted.original, 5);
+    assert!(!adjusted.was_adjusted());
+}
+
+#[test]
+fn clamp_integers() {
+    assert_eq!((-5).clamp_to_range(0 .. 10).unwrap().value, 0);
+    assert_eq!(15.clamp_to_range(0 .. 10).unwrap().value, 9);
+    assert_eq!(15.clamp_to_range(0 ..= 10).unwrap().value, 10);
+    assert_eq!((-5).clamp_to_range((Excluded(0), Included(10))).unwrap().value, 1);
+}
+
+#[test]
+fn clamp_unbounded() {
+    assert_eq!((-5).clamp_to_range(0 ..).unwrap().value, 0);
+    assert_eq!(i32::MAX.clamp_to_range(0 ..).unwrap().adjustment, Adjustment::Unchanged);
+    assert_eq!(15.clamp_to_range(.. 10).unwrap().value, 9);
+}
+
+#[test]
+fn clamp_floats() {
+    assert_eq!(1.5_f64.clamp_to_range(0.0 ..= 1.0).unwrap().value, 1.0);
+    assert_eq!((-0.5_f64).clamp_to_range(0.0 ..= 1.0).unwrap().value, 0.0);
+
+    let just_under = 1.5_f64.clamp_to_range(0.0 .. 1.0).unwrap().value;
+    assert!(just_under < 1.0);
+    assert!(just_under > 0.999_999_999);
+}
+
+#[test]
+fn clamp_adjustment() {
+    let adjusted = 120_u8.clamp_to_range(0 ..= 100).unwrap();
+    assert_eq!(adjusted.adjustment, Adjustment::Clamped);
+    assert_eq!(adjusted.original, 120);
+    assert!(adjusted.was_adjusted());
+}
+
+#[test]
+fn clamp_empty() {
+    assert_eq!(5.clamp_to_range(3 .. 3).unwrap_err().kind, ErrorKind::EmptyRange);
+    assert_eq!(5.clamp_to_range((Excluded(3), Excluded(4))).unwrap_err().kind, ErrorKind::EmptyRange);
+    assert_eq!(5.clamp_to_range((Included(9), Included(1))).unwrap_err().kind, ErrorKind::InvertedRange);
+}
+
+#[test]
+fn clamp_extremes() {
+    assert_eq!(255_u8.clamp_to_range((Excluded(0), Excluded(255))).unwrap().value, 254);
+    assert_eq!(0_u8.clamp_to_range((Excluded(0), Excluded(255))).unwrap().value, 1);
+    assert!(0_u8.clamp_to_range((Excluded(255), Unbounded)).is_err());
+}
+
+#[test]
+fn clamp_nan() {
+    assert!(f64::NAN.clamp_to_range(0.0 .. 1.0).is_err());
+}
+
+
+// Wrapping
+
+#[test]
+fn wrap_hours() {
+    assert_eq!(24.wrap_to_range(0 .. 24).unwrap().value, 0);
+    assert_eq!(50.wrap_to_range(0 .. 24).unwrap().value, 2);
+    assert_eq!((-1).wrap_to_range(0 .. 24).unwrap().value, 23);
+    assert_eq!((-25).wrap_to_range(0 .. 24).unwrap().value, 23);
+    assert_eq!(13.wrap_to_range(1 ..= 12).unwrap().value, 1);
+    assert_eq!(0.wrap_to_range(1 ..= 12).unwrap().value, 12);
+}
+
+#[test]
+fn wrap_adjustment() {
+    let adjusted = 25.wrap_to_range(0 .. 24).unwrap();
+    assert_eq!(adjusted.adjustment, Adjustment::Wrapped);
+    assert_eq!(adjusted.original, 25);
+    assert_eq!(3.wrap_to_range(0 .. 24).unwrap().adjustment, Adjustment::Unchanged);
+}
+
+#[test]
+fn wrap_unsigned_extremes() {
+    assert_eq!(255_u8.wrap_to_range(10 .. 20).unwrap().value, 15);
+    assert_eq!(u64::MAX.wrap_to_range(0 .. 10).unwrap().value, 5);
+    assert_eq!(i64::MIN.wrap_to_range(0 .. 10).unwrap().value, 2);
+}
+
+#[test]
+fn wrap_angles() {
+    assert_eq!(360.0_f64.wrap_to_range(0.0 .. 360.0).unwrap().value, 0.0);
+    assert_eq!(450.0_f64.wrap_to_range(0.0 .. 360.0).unwrap().value, 90.0);
+    assert_eq!((-90.0_f64).wrap_to_range(0.0 .. 360.0).unwrap().value, 270.0);
+    assert_eq!(270.0_f64.wrap_to_range(-180.0 .. 180.0).unwrap().value, -90.0);
+}
+
+#[test]
+fn wrap_excluded_lower_float() {
+    assert_eq!(720.0_f64.wrap_to_range((Excluded(0.0), Included(360.0))).unwrap().value, 360.0);
+}
+
+#[test]
+fn wrap_unbounded() {
+    assert_eq!((-5).wrap_to_range(0 ..).unwrap_err().kind, ErrorKind::Outside);
+    assert_eq!(5.wrap_to_range(.. 0).unwrap_err().kind, ErrorKind::Outside);
+}
+
+#[test]
+fn wrap_infinite() {
+    assert!(f64::INFINITY.wrap_to_range(0.0 .. 360.0).is_err());
+    assert!(f64::NAN.wrap_to_range(0.0 .. 360.0).is_err());
+}
+
+
+// Reflecting
+
+#[test]
+fn reflect_integers() {
+    assert_eq!(12.reflect_into_range(0 ..= 10).unwrap().value, 8);
+    assert_eq!(22.reflect_into_range(0 ..= 10).unwrap().value, 2);
+    assert_eq!((-3).reflect_into_range(0 ..= 10).unwrap().value, 3);
+    assert_eq!((-13).reflect_into_range(0 ..= 10).unwrap().value, 7);
+    assert_eq!(12.reflect_into_range(0 .. 10).unwrap().value, 6);
+}
+
+#[test]
+fn reflect_singleton() {
+    assert_eq!(12.reflect_into_range(5 ..= 5).unwrap().value, 5);
+}
+
+#[test]
+fn reflect_one_sided() {
+    assert_eq!((-3).reflect_into_range(0 ..).unwrap().value, 3);
+    assert_eq!(13.reflect_into_range(..= 10).unwrap().value, 7);
+    assert_eq!(0_u8.reflect_into_range(200 ..).unwrap().value, 255);
+}
+
+#[test]
+fn reflect_floats() {
+    assert_eq!(1.25_f64.reflect_into_range(0.0 ..= 1.0).unwrap().value, 0.75);
+    assert_eq!((-0.25_f64).reflect_into_range(0.0 ..= 1.0).unwrap().value, 0.25);
+    assert_eq!(2.0_f64.reflect_into_range(0.0 ..= 1.0).unwrap().value, 0.0);
+}
+
+#[test]
+fn reflect_onto_excluded_float() {
+    let value = 2.0_f64.reflect_into_range((Excluded(0.0), Included(1.0))).unwrap().value;
+    assert!(value > 0.0);
+}
+
+#[test]
+fn reflect_float_past_the_maximum() {
+    let adjusted = (-f64::MAX).reflect_into_range(f64::MAX / 2.0 ..).unwrap();
+    assert_eq!(adjusted.value, f64::MAX);
+    assert_eq!(adjusted.adjustment, Adjustment::Reflected);
+
+    assert_eq!(f32::MAX.reflect_into_range(.. -f32::MAX / 2.0).unwrap().value, f32::MIN);
+}
+
+#[test]
+fn reflect_float_too_wide() {
+    let err = f64::MAX.reflect_into_range(-f64::MAX ..= f64::MAX / 2.0).unwrap_err();
+    assert_eq!(err.kind, ErrorKind::Outside);
+}
+
+#[test]
+fn reflect_adjustment() {
+    let adjusted = 12.reflect_into_range(0 ..= 10).unwrap();
+    assert_eq!(adjusted.adjustment, Adjustment::Reflected);
+    assert_eq!(adjusted.original, 12);
+}
+
+#[test]
+fn reflect_empty() {
+    assert_eq!(5.reflect_into_range(3 .. 3).unwrap_err().kind, ErrorKind::EmptyRange);
+}
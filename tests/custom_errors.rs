@@ -0,0 +1,82 @@
+extern crate range_check;
+use range_check::{AnyOutOfRangeError, Bounds, Check, OutOfRangeError, RangeError, Violation};
+
+use std::error::Error;
+
+
+#[derive(PartialEq, Debug)]
+enum ClockError {
+    Hour(u8),
+    Minute(u8),
+}
+
+#[derive(PartialEq, Debug)]
+struct Details {
+    value: u8,
+    range: Bounds<u8>,
+    violation: Violation,
+}
+
+impl RangeError<u8> for Details {
+    fn from_range_error(error: OutOfRangeError<u8>) -> Self {
+        Details { violation: error.violation(), value: error.outside_value, range: error.allowed_range }
+    }
+}
+
+
+#[test]
+fn or_passes() {
+    assert_eq!(5_u8.check_range_or(0..24, ClockError::Hour(5)), Ok(5));
+}
+
+#[test]
+fn or_fails() {
+    let minute = 61_u8;
+    assert_eq!(minute.check_range_or(0..60, ClockError::Minute(minute)), Err(ClockError::Minute(61)));
+}
+
+#[test]
+fn with_gets_error() {
+    let result = 30_u8.check_range_with(0..24, |e| ClockError::Hour(e.outside_value));
+    assert_eq!(result, Err(ClockError::Hour(30)));
+}
+
+#[test]
+fn with_is_lazy() {
+    let mut called = false;
+    assert!(3_u8.check_range_with(0..24, |_| { called = true; ClockError::Hour(3) }).is_ok());
+    assert!(! called);
+}
+
+#[test]
+fn with_violation() {
+    let result = 30_u8.check_range_with(0..24, |e| e.violation());
+    assert_eq!(result, Err(Violation::AboveUpper));
+}
+
+#[test]
+fn as_custom_type() {
+    let err: Details = 200_u8.check_range_as(10..=100).unwrap_err();
+    assert_eq!(err, Details { value: 200, range: Bounds::closed(10, 100), violation: Violation::AboveUpper });
+}
+
+#[test]
+fn as_out_of_range_error() {
+    let result: Result<u8, OutOfRangeError<u8>> = 5_u8.check_range_as(0..5);
+    assert_eq!(result, 5_u8.check_range(0..5));
+}
+
+#[test]
+fn as_any_error() {
+    let err = 'z'.check_range_as::<AnyOutOfRangeError>('a'..'m').unwrap_err();
+    assert!(err.is::<char>());
+}
+
+#[test]
+fn as_boxed_error() {
+    fn check(value: i32) -> Result<i32, Box<dyn Error + Send + Sync>> {
+        value.check_range_as(0..10)
+    }
+
+    assert_eq!(check(10).unwrap_err().to_string(), "value (10) above range (0..10)");
+}
@@ -0,0 +1,68 @@
+// These tests deliberately check against inverted ranges.
+#![allow(clippy::reversed_empty_ranges)]
+
+extern crate range_check;
+use range_check::{Bounds, Check, ErrorKind};
+
+
+#[test]
+fn empty() {
+    assert!( Bounds::half_open(3, 3).is_empty());
+    assert!( Bounds::open(3, 3).is_empty());
+    assert!(!Bounds::closed(3, 3).is_empty());
+    assert!( Bounds::closed(4, 3).is_empty());
+    assert!(!Bounds::at_least(3).is_empty());
+    assert!(!Bounds::<i32>::unbounded().is_empty());
+}
+
+#[test]
+fn singleton() {
+    assert!( Bounds::closed(3, 3).is_singleton());
+    assert!(!Bounds::half_open(3, 3).is_singleton());
+    assert!(!Bounds::open(3, 3).is_singleton());
+    assert!(!Bounds::closed(3, 4).is_singleton());
+    assert!(!Bounds::at_most(3).is_singleton());
+}
+
+#[test]
+fn inverted() {
+    assert!( Bounds::closed(4, 3).is_inverted());
+    assert!( Bounds::open(4, 3).is_inverted());
+    assert!(!Bounds::half_open(3, 3).is_inverted());
+    assert!(!Bounds::closed(3, 4).is_inverted());
+    assert!(!Bounds::at_least(4).is_inverted());
+}
+
+
+#[test]
+fn value_outside() {
+    let err = 4.check_range(1 .. 3).unwrap_err();
+    assert_eq!(err.kind, ErrorKind::Outside);
+    assert_eq!(err.to_string(), "value (4) above range (1..3)");
+}
+
+#[test]
+fn range_inverted() {
+    let err = 4.check_range(5 .. 3).unwrap_err();
+    assert_eq!(err.kind, ErrorKind::InvertedRange);
+    assert_eq!(err.to_string(), "range (5..3) is inverted, so cannot contain value (4)");
+}
+
+#[test]
+fn range_empty() {
+    let err = 4.check_range(4 .. 4).unwrap_err();
+    assert_eq!(err.kind, ErrorKind::EmptyRange);
+    assert_eq!(err.to_string(), "range (4..4) is empty, so cannot contain value (4)");
+}
+
+#[test]
+fn inclusive_singleton_still_checks() {
+    assert_eq!(4.check_range(4 ..= 4), Ok(4));
+    assert_eq!(5.check_range(4 ..= 4).unwrap_err().kind, ErrorKind::Outside);
+}
+
+#[test]
+fn kind_survives_generify() {
+    let err = 4_i8.check_range(5 .. 3).unwrap_err().generify::<i32>();
+    assert_eq!(err.kind, ErrorKind::InvertedRange);
+}
@@ -0,0 +1,115 @@
+extern crate range_check;
+use range_check::{Bounds, BoundsFormat, Check, IntervalNotation, RangeSet};
+
+use std::ops::Bound;
+
+
+fn all_formats(bounds: Bounds<i32>) -> [String; 4] {
+    [
+        bounds.display(&BoundsFormat::Rust).to_string(),
+        bounds.display(&BoundsFormat::Interval(IntervalNotation::new())).to_string(),
+        bounds.display(&BoundsFormat::Inequality).to_string(),
+        bounds.display(&BoundsFormat::English).to_string(),
+    ]
+}
+
+
+#[test]
+fn closed() {
+    assert_eq!(all_formats(Bounds::closed(1, 5)),
+               [ "1..=5", "[1, 5]", "1 <= x <= 5", "at least 1 and at most 5" ]);
+}
+
+#[test]
+fn half_open() {
+    assert_eq!(all_formats(Bounds::half_open(1, 5)),
+               [ "1..5", "[1, 5)", "1 <= x < 5", "at least 1 and less than 5" ]);
+}
+
+#[test]
+fn open() {
+    assert_eq!(all_formats(Bounds::open(1, 5)),
+               [ "1<..5", "(1, 5)", "1 < x < 5", "greater than 1 and less than 5" ]);
+}
+
+#[test]
+fn excluded_lower_included_upper() {
+    let bounds = Bounds { lower: Bound::Excluded(5), upper: Bound::Included(10) };
+    assert_eq!(all_formats(bounds),
+               [ "5<..=10", "(5, 10]", "5 < x <= 10", "greater than 5 and at most 10" ]);
+}
+
+#[test]
+fn excluded_lower_only() {
+    let bounds = Bounds { lower: Bound::Excluded(5), upper: Bound::Unbounded };
+    assert_eq!(all_formats(bounds),
+               [ "5<..", "(5, ∞)", "5 < x", "greater than 5" ]);
+}
+
+#[test]
+fn at_least() {
+    assert_eq!(all_formats(Bounds::at_least(5)),
+               [ "5..", "[5, ∞)", "5 <= x", "at least 5" ]);
+}
+
+#[test]
+fn at_most() {
+    assert_eq!(all_formats(Bounds::at_most(5)),
+               [ "..=5", "(-∞, 5]", "x <= 5", "at most 5" ]);
+}
+
+#[test]
+fn below() {
+    let bounds = Bounds { lower: Bound::Unbounded, upper: Bound::Excluded(5) };
+    assert_eq!(all_formats(bounds),
+               [ "..5", "(-∞, 5)", "x < 5", "less than 5" ]);
+}
+
+#[test]
+fn unbounded() {
+    assert_eq!(all_formats(Bounds::unbounded()),
+               [ "..", "(-∞, ∞)", "-∞ < x < ∞", "any value" ]);
+}
+
+#[test]
+fn display_matches_rust_format() {
+    let bounds = Bounds::open(1, 5);
+    assert_eq!(bounds.to_string(), bounds.display(&BoundsFormat::default()).to_string());
+}
+
+#[test]
+fn rust_format_parses_back() {
+    let bounds = Bounds { lower: Bound::Excluded(5), upper: Bound::Included(10) };
+    assert_eq!(bounds.to_string().parse(), Ok(bounds));
+}
+
+
+#[test]
+fn error_excluded_lower() {
+    let range = Bounds { lower: Bound::Excluded(0), upper: Bound::Included(10) };
+    let err = 0.check_range(range).unwrap_err();
+    assert_eq!(err.to_string(), "value (0) below range (0<..=10)");
+    assert_eq!(err.display(&BoundsFormat::Inequality).to_string(), "value (0) below range (0 < x <= 10)");
+}
+
+#[test]
+fn error_english() {
+    let err = 61.check_range_named("minute", 0..60).unwrap_err();
+    assert_eq!(err.display(&BoundsFormat::English).to_string(),
+               "minute: value (61) above range (at least 0 and less than 60)");
+}
+
+#[test]
+fn error_empty() {
+    let err = 5.check_range(Bounds::open(5, 5)).unwrap_err();
+    assert_eq!(err.display(&BoundsFormat::Inequality).to_string(),
+               "range (5 < x < 5) is empty, so cannot contain value (5)");
+}
+
+#[test]
+fn error_set() {
+    let set: RangeSet<i32> = vec![ Bounds::half_open(0, 5), Bounds::at_least(10) ].into_iter().collect();
+    let err = set.check(7).unwrap_err();
+    assert_eq!(err.display(&BoundsFormat::Inequality).to_string(),
+               "value (7) between ranges (0 <= x < 5, 10 <= x)");
+}
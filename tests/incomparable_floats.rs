@@ -0,0 +1,99 @@
+extern crate range_check;
+use range_check::{Bounds, Check, Coerce, ErrorKind, RangeSet, TotalOrder, Violation};
+
+
+// Partial order
+
+#[test]
+fn nan_is_incomparable() {
+    let err = f64::NAN.check_range(0.0 .. 1.0).unwrap_err();
+    assert_eq!(err.kind, ErrorKind::Incomparable);
+    assert_eq!(err.violation(), Violation::Incomparable);
+    assert_eq!(err.to_string(), "value (NaN) cannot be compared with range (0.0..1.0)");
+}
+
+#[test]
+fn nan_in_unbounded_range() {
+    let err = f32::NAN.check_range(0.0 ..).unwrap_err();
+    assert_eq!(err.kind, ErrorKind::Incomparable);
+}
+
+#[test]
+fn nan_bound_is_incomparable() {
+    let err = 0.5_f64.check_range(0.0 .. f64::NAN).unwrap_err();
+    assert_eq!(err.kind, ErrorKind::Incomparable);
+}
+
+#[test]
+fn infinity_is_comparable() {
+    let err = f64::INFINITY.check_range(0.0 .. 1.0).unwrap_err();
+    assert_eq!(err.kind, ErrorKind::Outside);
+    assert_eq!(err.violation(), Violation::AboveUpper);
+}
+
+#[test]
+fn negative_zero_is_zero() {
+    assert!((-0.0_f64).check_range(0.0 .. 1.0).is_ok());
+}
+
+#[test]
+fn nan_against_set() {
+    let set: RangeSet<f64> = vec![ Bounds::closed(0.0, 1.0) ].into_iter().collect();
+    assert_eq!(set.check(f64::NAN).unwrap_err().kind, ErrorKind::Incomparable);
+}
+
+#[test]
+fn nan_cannot_be_clamped() {
+    assert_eq!(f64::NAN.clamp_to_range(0.0 ..= 1.0).unwrap_err().kind, ErrorKind::Incomparable);
+}
+
+
+// Total order
+
+fn total(lower: f64, upper: f64) -> std::ops::RangeInclusive<TotalOrder<f64>> {
+    TotalOrder(lower) ..= TotalOrder(upper)
+}
+
+fn total_f32(lower: f32, upper: f32) -> std::ops::RangeInclusive<TotalOrder<f32>> {
+    TotalOrder(lower) ..= TotalOrder(upper)
+}
+
+#[test]
+fn total_ordinary() {
+    assert!(TotalOrder(0.5).check_range(total(0.0, 1.0)).is_ok());
+    assert!(TotalOrder(1.5).check_range(total(0.0, 1.0)).is_err());
+}
+
+#[test]
+fn total_positive_nan_is_above() {
+    let err = TotalOrder(f64::NAN).check_range(total(0.0, 1.0)).unwrap_err();
+    assert_eq!(err.kind, ErrorKind::Outside);
+    assert_eq!(err.violation(), Violation::AboveUpper);
+    assert_eq!(err.to_string(), "value (NaN) above range (0.0..=1.0)");
+}
+
+#[test]
+fn total_negative_nan_is_below() {
+    let err = TotalOrder(-f64::NAN).check_range(total(0.0, 1.0)).unwrap_err();
+    assert_eq!(err.violation(), Violation::BelowLower);
+}
+
+#[test]
+fn total_nan_above_infinity() {
+    assert!(TotalOrder(f64::NAN).check_range(TotalOrder(0.0) ..).is_ok());
+    assert!(TotalOrder(f64::NAN).check_range(total(0.0, f64::INFINITY)).is_err());
+    assert!(TotalOrder(f64::NAN).check_range(total(0.0, f64::NAN)).is_ok());
+}
+
+#[test]
+fn total_negative_zero() {
+    assert!(TotalOrder(-0.0_f32).check_range(total_f32(0.0, 1.0)).is_err());
+    assert!(TotalOrder(0.0_f32).check_range(total_f32(0.0, 1.0)).is_ok());
+    assert!(TotalOrder(-0.0_f32).check_range(total_f32(-0.0, 1.0)).is_ok());
+}
+
+#[test]
+fn total_equality() {
+    assert_eq!(TotalOrder(f64::NAN), TotalOrder(f64::NAN));
+    assert_ne!(TotalOrder(0.0), TotalOrder(-0.0));
+}
@@ -0,0 +1,111 @@
+extern crate range_check;
+use range_check::{Bounds, ErrorKind, IntervalMap, Violation};
+
+
+fn tax_brackets() -> IntervalMap<u32, u8> {
+    let mut map = IntervalMap::new();
+    map.insert(Bounds::half_open(0, 12_500), 0).unwrap();
+    map.insert(Bounds::half_open(50_000, 150_000), 40).unwrap();
+    map.insert(Bounds::half_open(12_500, 50_000), 20).unwrap();
+    map.insert(Bounds::at_least(150_000), 45).unwrap();
+    map
+}
+
+
+#[test]
+fn lookups() {
+    let map = tax_brackets();
+    assert_eq!(map.get(&0), Some(&0));
+    assert_eq!(map.get(&12_499), Some(&0));
+    assert_eq!(map.get(&12_500), Some(&20));
+    assert_eq!(map.get(&49_999), Some(&20));
+    assert_eq!(map.get(&50_000), Some(&40));
+    assert_eq!(map.get(&1_000_000), Some(&45));
+}
+
+#[test]
+fn key_values() {
+    let map = tax_brackets();
+    assert_eq!(map.get_key_value(&20_000), Some((&Bounds::half_open(12_500, 50_000), &20)));
+}
+
+#[test]
+fn sorted_iteration() {
+    let map = tax_brackets();
+    let rates: Vec<u8> = map.iter().map(|(_, v)| *v).collect();
+    assert_eq!(rates, vec![ 0, 20, 40, 45 ]);
+    assert_eq!(map.len(), 4);
+}
+
+#[test]
+fn gaps() {
+    let mut map = IntervalMap::new();
+    map.insert(Bounds::closed(1, 3), 'a').unwrap();
+    map.insert(Bounds::open(3, 5), 'b').unwrap();
+    map.insert(Bounds::closed(7, 9), 'c').unwrap();
+
+    assert_eq!(map.get(&0), None);
+    assert_eq!(map.get(&3), Some(&'a'));
+    assert_eq!(map.get(&5), None);
+    assert_eq!(map.get(&6), None);
+    assert_eq!(map.get(&10), None);
+}
+
+#[test]
+fn overlap_rejected() {
+    let mut map = tax_brackets();
+    let err = map.insert(Bounds::closed(40_000, 60_000), 30).unwrap_err();
+    assert_eq!(err.existing_range, Bounds::half_open(12_500, 50_000));
+    assert_eq!(map.len(), 4);
+}
+
+#[test]
+fn overlap_at_included_endpoint() {
+    let mut map = IntervalMap::new();
+    map.insert(Bounds::closed(1, 3), ()).unwrap();
+    assert!(map.insert(Bounds::closed(3, 5), ()).is_err());
+    assert!(map.insert(Bounds::open(3, 5), ()).is_ok());
+}
+
+#[test]
+fn overlap_message() {
+    let mut map = IntervalMap::new();
+    map.insert(Bounds::half_open(1, 5), ()).unwrap();
+    assert_eq!(map.insert(Bounds::half_open(4, 8), ()).unwrap_err().to_string(),
+               "range (4..8) overlaps existing range (1..5)");
+}
+
+#[test]
+fn remove() {
+    let mut map = tax_brackets();
+    assert_eq!(map.remove(&Bounds::half_open(12_500, 50_000)), Some(20));
+    assert_eq!(map.remove(&Bounds::half_open(12_500, 50_000)), None);
+    assert_eq!(map.get(&20_000), None);
+}
+
+#[test]
+fn checked_ok() {
+    let map = tax_brackets();
+    assert_eq!(map.get_checked(75_000), Ok(&40));
+}
+
+#[test]
+fn checked_gap() {
+    let mut map = IntervalMap::new();
+    map.insert(Bounds::half_open(0, 10), 'a').unwrap();
+    map.insert(Bounds::half_open(10, 20), 'b').unwrap();
+    map.insert(Bounds::half_open(30, 40), 'c').unwrap();
+
+    let err = map.get_checked(25).unwrap_err();
+    assert_eq!(err.allowed_range, Bounds::half_open(0, 40));
+    assert_eq!(err.kind, ErrorKind::OutsideSet(vec![ Bounds::half_open(0, 20), Bounds::half_open(30, 40) ]));
+    assert_eq!(err.to_string(), "value (25) between ranges (0..20, 30..40)");
+}
+
+#[test]
+fn checked_empty() {
+    let map: IntervalMap<i32, ()> = IntervalMap::new();
+    let err = map.get_checked(5).unwrap_err();
+    assert_eq!(err.to_string(), "value (5) outside of empty range set");
+    assert_eq!(err.violation(), Violation::EmptySet);
+}
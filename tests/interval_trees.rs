@@ -0,0 +1,141 @@
+extern crate range_check;
+use range_check::{Bounds, IntervalTree};
+
+use std::ops::Bound::*;
+
+
+// A small deterministic pseudo-random number generator, so the tree can be
+// compared against a plain list of ranges without pulling in a dependency.
+struct Lcg(u64);
+
+impl Lcg {
+    fn next(&mut self, limit: u64) -> i32 {
+        self.0 = self.0.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        ((self.0 >> 33) % limit) as i32
+    }
+
+    fn range(&mut self) -> Bounds<i32> {
+        let a = self.next(100);
+        let b = a + self.next(20);
+        let lower = match self.next(5) { 0 => Unbounded, 1 | 2 => Excluded(a), _ => Included(a) };
+        let upper = match self.next(5) { 0 => Unbounded, 1 | 2 => Excluded(b), _ => Included(b) };
+        Bounds { lower, upper }
+    }
+}
+
+fn sorted<'a>(mut found: Vec<(&'a Bounds<i32>, &'a usize)>) -> Vec<usize> {
+    found.sort_by_key(|e| *e.1);
+    found.into_iter().map(|e| *e.1).collect()
+}
+
+
+#[test]
+fn empty() {
+    let tree: IntervalTree<i32, ()> = IntervalTree::new();
+    assert!(tree.is_empty());
+    assert!(tree.containing(&3).is_empty());
+    assert!(tree.overlapping(&Bounds::unbounded()).is_empty());
+    assert_eq!(tree.iter().count(), 0);
+}
+
+#[test]
+fn stabbing() {
+    let mut tree = IntervalTree::new();
+    tree.insert(Bounds::closed(1, 5), 'a');
+    tree.insert(Bounds::half_open(3, 8), 'b');
+    tree.insert(Bounds::open(5, 9), 'c');
+    tree.insert(Bounds::at_least(8), 'd');
+
+    let at = |x| tree.containing(&x).into_iter().map(|e| *e.1).collect::<Vec<_>>();
+    assert_eq!(at(0), vec![]);
+    assert_eq!(at(3), vec![ 'a', 'b' ]);
+    assert_eq!(at(5), vec![ 'a', 'b' ]);
+    assert_eq!(at(8), vec![ 'c', 'd' ]);
+    assert_eq!(at(100), vec![ 'd' ]);
+}
+
+#[test]
+fn overlapping() {
+    let mut tree = IntervalTree::new();
+    tree.insert(Bounds::closed(1, 5), 'a');
+    tree.insert(Bounds::half_open(10, 15), 'b');
+    tree.insert(Bounds::at_most(0), 'c');
+
+    let over = |r| tree.overlapping(&r).into_iter().map(|e| *e.1).collect::<Vec<_>>();
+    assert_eq!(over(Bounds::closed(5, 10)), vec![ 'a', 'b' ]);
+    assert_eq!(over(Bounds::open(5, 10)), vec![]);
+    assert_eq!(over(Bounds::closed(-3, 1)), vec![ 'c', 'a' ]);
+    assert_eq!(over(Bounds::at_least(15)), vec![]);
+    assert_eq!(over(Bounds::half_open(3, 3)), vec![]);
+}
+
+#[test]
+fn duplicates() {
+    let mut tree = IntervalTree::new();
+    tree.insert(Bounds::closed(1, 5), 'a');
+    tree.insert(Bounds::closed(1, 5), 'b');
+    assert_eq!(tree.len(), 2);
+    assert_eq!(tree.containing(&3).len(), 2);
+
+    assert!(tree.remove(&Bounds::closed(1, 5)).is_some());
+    assert_eq!(tree.len(), 1);
+    assert_eq!(tree.containing(&3).len(), 1);
+}
+
+#[test]
+fn remove_missing() {
+    let mut tree = IntervalTree::new();
+    tree.insert(Bounds::closed(1, 5), 'a');
+    assert_eq!(tree.remove(&Bounds::closed(1, 6)), None);
+    assert_eq!(tree.len(), 1);
+}
+
+#[test]
+fn iteration_is_sorted() {
+    let mut tree = IntervalTree::new();
+    for n in (0 .. 100).rev() {
+        tree.insert(Bounds::closed(n, n + 10), n);
+    }
+
+    let values: Vec<i32> = tree.iter().map(|e| *e.1).collect();
+    assert_eq!(values, (0 .. 100).collect::<Vec<_>>());
+}
+
+#[test]
+fn against_a_list() {
+    let mut rng = Lcg(1234);
+    let mut tree = IntervalTree::new();
+    let mut list: Vec<(Bounds<i32>, usize)> = Vec::new();
+
+    for id in 0 .. 2000 {
+        if rng.next(4) == 0 && ! list.is_empty() {
+            let index = rng.next(list.len() as u64) as usize;
+            let range = list[index].0.clone();
+
+            // The tree removes any one of the identical ranges, so remove
+            // whichever one it picked from the list.
+            let removed = tree.remove(&range).unwrap();
+            list.retain(|e| e.1 != removed);
+        }
+        else {
+            let range = rng.range();
+            tree.insert(range.clone(), id);
+            list.push((range, id));
+        }
+
+        assert_eq!(tree.len(), list.len());
+    }
+
+    for x in -5 .. 130 {
+        let mut expected: Vec<usize> = list.iter().filter(|e| e.0.contains(&x)).map(|e| e.1).collect();
+        expected.sort();
+        assert_eq!(sorted(tree.containing(&x)), expected, "containing {}", x);
+    }
+
+    for _ in 0 .. 200 {
+        let query = rng.range();
+        let mut expected: Vec<usize> = list.iter().filter(|e| e.0.overlaps(&query)).map(|e| e.1).collect();
+        expected.sort();
+        assert_eq!(sorted(tree.overlapping(&query)), expected, "overlapping {}", query);
+    }
+}
@@ -0,0 +1,237 @@
+extern crate range_check;
+use range_check::{Bounds, BoundsFormat, Check, IntervalNotation, ParseErrorKind, RangeSet};
+
+extern crate quickcheck;
+use quickcheck::quickcheck;
+
+use std::ops::Bound;
+
+
+fn show(bounds: Bounds<i32>) -> String {
+    bounds.display(&BoundsFormat::Interval(IntervalNotation::new())).to_string()
+}
+
+fn parse(input: &str) -> Bounds<i32> {
+    IntervalNotation::new().parse(input).unwrap()
+}
+
+
+#[test]
+fn display_closed() {
+    assert_eq!(show(Bounds::closed(1, 5)), "[1, 5]");
+}
+
+#[test]
+fn display_open() {
+    assert_eq!(show(Bounds::open(1, 5)), "(1, 5)");
+}
+
+#[test]
+fn display_half_open() {
+    assert_eq!(show(Bounds::half_open(0, 24)), "[0, 24)");
+}
+
+#[test]
+fn display_unbounded() {
+    assert_eq!(show(Bounds::at_most(5)), "(-∞, 5]");
+    assert_eq!(show(Bounds::unbounded()), "(-∞, ∞)");
+}
+
+#[test]
+fn display_reversed() {
+    let format = BoundsFormat::Interval(IntervalNotation::new().reversed_brackets().separator(";"));
+    assert_eq!(Bounds::half_open(1, 5).display(&format).to_string(), "[1;5[");
+    assert_eq!(Bounds::at_most(5).display(&format).to_string(), "]-∞;5]");
+}
+
+#[test]
+fn display_ascii_infinity() {
+    let format = BoundsFormat::Interval(IntervalNotation::new().infinity("inf"));
+    assert_eq!(Bounds::at_least(0).display(&format).to_string(), "[0, inf)");
+}
+
+#[test]
+fn runtime_strings() {
+    let separator = String::from(" | ");
+    let infinity = String::from("unendlich");
+    let notation = IntervalNotation::new().separator(separator).infinity(infinity);
+    let format = BoundsFormat::Interval(notation.clone());
+    assert_eq!(Bounds::at_least(0).display(&format).to_string(), "[0 | unendlich)");
+    assert_eq!(notation.parse("[0|unendlich)"), Ok(Bounds::at_least(0)));
+}
+
+
+#[test]
+fn parse_both_styles() {
+    let excluded = Bounds { lower: Bound::Excluded(1), upper: Bound::Included(5) };
+    assert_eq!(parse("(1, 5]"), excluded);
+    assert_eq!(parse("]1, 5]"), excluded);
+    assert_eq!(parse("[1, 5)"), parse("[1, 5["));
+}
+
+#[test]
+fn parse_infinities() {
+    assert_eq!(parse("(0, ∞)"), Bounds { lower: Bound::Excluded(0), upper: Bound::Unbounded });
+    assert_eq!(parse("(-inf, +inf)"), Bounds::unbounded());
+    assert_eq!(parse("(−∞, 3]"), Bounds::at_most(3));
+    assert_eq!(parse("(-INF, 3]"), Bounds::at_most(3));
+}
+
+#[test]
+fn parse_custom_infinity() {
+    let notation = IntervalNotation::new().infinity("forever");
+    assert_eq!(notation.parse("[0, forever)"), Ok(Bounds::at_least(0)));
+}
+
+#[test]
+fn parse_dash_separator() {
+    let notation = IntervalNotation::new().separator(" - ");
+    assert_eq!(notation.parse("[-5 - -1]"), Ok(Bounds::closed(-5, -1)));
+    assert_eq!(notation.parse("[-5--1]"), Ok(Bounds::closed(-5, -1)));
+    assert_eq!(notation.parse("(-∞ - -1]"), Ok(Bounds::at_most(-1)));
+
+    let notation = IntervalNotation::new().separator("-");
+    assert_eq!(notation.parse("[-5 - -1]"), Ok(Bounds::closed(-5, -1)));
+}
+
+#[test]
+fn parse_included_infinity() {
+    let notation = IntervalNotation::new();
+    let included = Bounds { lower: Bound::Included(0.0), upper: Bound::Included(f64::INFINITY) };
+    assert_eq!(notation.parse("[0, inf]"), Ok(included));
+    assert_eq!(notation.parse("[0, ∞)"), Ok(Bounds::at_least(0.0)));
+    assert_eq!(notation.parse::<i32>("[0, ∞]").unwrap_err().offset, 4);
+}
+
+#[test]
+fn parse_whitespace() {
+    assert_eq!(parse("  [ -3 ,4 ]  "), Bounds::closed(-3, 4));
+}
+
+#[test]
+fn parse_separator() {
+    let notation = IntervalNotation::new().separator("; ");
+    assert_eq!(notation.parse("]1;5]"), Ok(Bounds { lower: Bound::Excluded(1), upper: Bound::Included(5) }));
+    assert_eq!(notation.parse("[1 ; 5]"), Ok(Bounds::closed(1, 5)));
+}
+
+#[test]
+fn parse_floats() {
+    assert_eq!(IntervalNotation::new().parse("[0.5, 1.5)"), Ok(Bounds::half_open(0.5, 1.5)));
+}
+
+
+#[test]
+fn no_opening_bracket() {
+    let err = IntervalNotation::new().parse::<i32>("1, 5]").unwrap_err();
+    assert_eq!((err.offset, err.kind), (0, ParseErrorKind::MissingBracket));
+}
+
+#[test]
+fn no_closing_bracket() {
+    let err = IntervalNotation::new().parse::<i32>(" [1, 5").unwrap_err();
+    assert_eq!((err.offset, err.kind), (5, ParseErrorKind::MissingBracket));
+}
+
+#[test]
+fn no_separator() {
+    let err = IntervalNotation::new().parse::<i32>("[1 5]").unwrap_err();
+    assert_eq!((err.offset, err.kind), (1, ParseErrorKind::MissingSeparator));
+}
+
+#[test]
+fn missing_value() {
+    let err = IntervalNotation::new().parse::<i32>("[1,  ]").unwrap_err();
+    assert_eq!((err.offset, err.kind), (5, ParseErrorKind::MissingValue));
+}
+
+#[test]
+fn invalid_value() {
+    let err = IntervalNotation::new().parse::<i32>("[1, ∞∞)").unwrap_err();
+    assert_eq!(err.offset, 4);
+    assert_eq!(err.to_string(), "invalid value at byte 4: invalid digit found in string");
+}
+
+#[test]
+fn unsigned_lower_infinity() {
+    let err = IntervalNotation::new().parse::<i32>("(∞, 5]").unwrap_err();
+    assert_eq!(err.offset, 1);
+}
+
+
+#[test]
+fn error_message() {
+    let err = 24.check_range(0..24).unwrap_err();
+    assert_eq!(err.display(&BoundsFormat::Interval(IntervalNotation::new())).to_string(), "value (24) above range ([0, 24))");
+}
+
+#[test]
+fn error_message_with_path() {
+    let err = (-1).check_range_named("hour", 0..).unwrap_err();
+    let format = BoundsFormat::Interval(IntervalNotation::new().reversed_brackets().separator("; "));
+    assert_eq!(err.display(&format).to_string(), "hour: value (-1) below range ([0; ∞[)");
+}
+
+#[test]
+fn error_message_with_set() {
+    let set: RangeSet<i32> = vec![ Bounds::half_open(0, 5), Bounds::closed(10, 15) ].into_iter().collect();
+    let err = set.check(7).unwrap_err();
+    assert_eq!(err.display(&BoundsFormat::Interval(IntervalNotation::new())).to_string(), "value (7) between ranges ([0, 5), [10, 15])");
+}
+
+
+#[test]
+fn round_trip() {
+    fn prop(lower_kind: u8, lower: i64, upper_kind: u8, upper: i64, reversed: bool) -> bool {
+        let bound = |kind: u8, value| match kind % 3 {
+            0  => Bound::Unbounded,
+            1  => Bound::Included(value),
+            _  => Bound::Excluded(value),
+        };
+
+        let notation = if reversed { IntervalNotation::new().reversed_brackets().separator(";") }
+                              else { IntervalNotation::new() };
+
+        let bounds = Bounds { lower: bound(lower_kind, lower), upper: bound(upper_kind, upper) };
+        let format = BoundsFormat::Interval(notation.clone());
+        notation.parse(&bounds.display(&format).to_string()) == Ok(bounds)
+    }
+
+    quickcheck(prop as fn(u8, i64, u8, i64, bool) -> bool);
+}
+
+#[test]
+fn round_trip_floats() {
+    fn prop(lower_kind: u8, lower: f64, upper_kind: u8, upper: f64, reversed: bool) -> bool {
+        if lower.is_nan() || upper.is_nan() {
+            return true;
+        }
+
+        let bound = |kind: u8, value| match kind % 3 {
+            0  => Bound::Unbounded,
+            1  => Bound::Included(value),
+            _  => Bound::Excluded(value),
+        };
+
+        let notation = if reversed { IntervalNotation::new().reversed_brackets().separator(";") }
+                              else { IntervalNotation::new() };
+
+        let bounds = Bounds { lower: bound(lower_kind, lower), upper: bound(upper_kind, upper) };
+        let format = BoundsFormat::Interval(notation.clone());
+        notation.parse(&bounds.display(&format).to_string()) == Ok(bounds)
+    }
+
+    quickcheck(prop as fn(u8, f64, u8, f64, bool) -> bool);
+}
+
+#[test]
+fn round_trip_infinite_floats() {
+    let notation = IntervalNotation::new();
+    let format = BoundsFormat::Interval(notation.clone());
+    for &lower in &[ Bound::Included(f64::NEG_INFINITY), Bound::Excluded(f64::NEG_INFINITY), Bound::Unbounded ] {
+        for &upper in &[ Bound::Included(f64::INFINITY), Bound::Excluded(f64::INFINITY), Bound::Unbounded ] {
+            let bounds = Bounds { lower, upper };
+            assert_eq!(notation.parse(&bounds.display(&format).to_string()), Ok(bounds));
+        }
+    }
+}
@@ -0,0 +1,107 @@
+extern crate range_check;
+use range_check::{Bounds, CheckBy, ErrorKind, Violation};
+
+use std::cmp::Ordering;
+
+
+#[derive(PartialEq, Debug, Clone)]
+struct Version {
+    major: u32,
+    minor: u32,
+    label: &'static str,
+}
+
+fn version(major: u32, minor: u32) -> Version {
+    Version { major, minor, label: "" }
+}
+
+fn by_number(a: &Version, b: &Version) -> Ordering {
+    (a.major, a.minor).cmp(&(b.major, b.minor))
+}
+
+
+// By key
+
+#[test]
+fn key_in_range_keeps_value() {
+    let v = Version { major: 1, minor: 4, label: "stable" };
+    assert_eq!(v.clone().check_range_by_key(|v| v.minor, 0..10), Ok(v));
+}
+
+#[test]
+fn key_out_of_range_shows_key() {
+    let err = version(2, 12).check_range_by_key(|v| v.minor, 0..10).unwrap_err();
+    assert_eq!(err.outside_value, 12);
+    assert_eq!(err.allowed_range, Bounds::half_open(0, 10));
+    assert_eq!(err.violation(), Violation::AboveUpper);
+}
+
+#[test]
+fn tuple_keys() {
+    let range = (1, 0) .. (2, 0);
+    assert!(version(1, 99).check_range_by_key(|v| (v.major, v.minor), range.clone()).is_ok());
+    assert_eq!(version(2, 0).check_range_by_key(|v| (v.major, v.minor), range).unwrap_err().to_string(),
+               "value ((2, 0)) above range ((1, 0)..(2, 0))");
+}
+
+#[test]
+fn string_keys() {
+    let err = "Hello".check_range_by_key(|s| s.len(), 6..).unwrap_err();
+    assert_eq!(err.outside_value, 5);
+}
+
+#[test]
+fn nan_key() {
+    let err = (0_u8, f64::NAN).check_range_by_key(|p| p.1, 0.0..1.0).unwrap_err();
+    assert_eq!(err.kind, ErrorKind::Incomparable);
+}
+
+
+// By comparator
+
+#[test]
+fn comparator_in_range() {
+    let range = version(1, 0) .. version(2, 0);
+    let v = Version { major: 1, minor: 7, label: "beta" };
+    assert_eq!(v.clone().check_range_by(by_number, range), Ok(v));
+}
+
+#[test]
+fn comparator_out_of_range() {
+    let range = version(1, 0) ..= version(1, 9);
+    let err = version(1, 10).check_range_by(by_number, range).unwrap_err();
+
+    assert_eq!(err.outside_value, version(1, 10));
+    assert_eq!(err.kind, ErrorKind::Outside);
+}
+
+#[test]
+fn comparator_ignores_other_fields() {
+    let range = version(1, 0) ..= version(1, 0);
+    let v = Version { major: 1, minor: 0, label: "anything" };
+    assert!(v.check_range_by(by_number, range).is_ok());
+}
+
+#[test]
+fn case_insensitive() {
+    let compare = |a: &&str, b: &&str| a.to_lowercase().cmp(&b.to_lowercase());
+    assert!("apple".check_range_by(compare, "A" ..= "M").is_ok());
+    assert!("APPLE".check_range_by(compare, "a" ..= "m").is_ok());
+    assert!("Zebra".check_range_by(compare, "a" ..= "m").is_err());
+}
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn reversed_order() {
+    let reversed = |a: &i32, b: &i32| b.cmp(a);
+    assert!(5.check_range_by(reversed, 10 ..= 1).is_ok());
+    assert!(5.check_range_by(reversed, 1 ..= 10).is_err());
+}
+
+#[test]
+fn comparator_empty_ranges() {
+    assert_eq!(version(1, 5).check_range_by(by_number, version(2, 0) .. version(1, 0)).unwrap_err().kind,
+               ErrorKind::InvertedRange);
+    assert_eq!(version(1, 5).check_range_by(by_number, version(1, 0) .. version(1, 0)).unwrap_err().kind,
+               ErrorKind::EmptyRange);
+}
@@ -0,0 +1,172 @@
+extern crate range_check;
+use range_check::{Bounds, Check, Discrete, EnglishCatalog, GermanCatalog, RangeSet};
+
+use std::ops::Bound;
+
+
+#[test]
+fn between() {
+    let err = 0.check_range(1 ..= 9998).unwrap_err();
+    assert_eq!(err.message().to_string(), "must be between 1 and 9998");
+}
+
+#[test]
+fn between_inclusive() {
+    let err = 24680.check_range(1 .. 9999).unwrap_err();
+    assert_eq!(err.message().inclusive().to_string(), "must be between 1 and 9998");
+}
+
+#[test]
+fn at_least() {
+    let err = (-1).check_range(0 ..).unwrap_err();
+    assert_eq!(err.message().to_string(), "must be at least 0");
+}
+
+#[test]
+fn less_than() {
+    let err = 60.check_range(.. 60).unwrap_err();
+    assert_eq!(err.message().to_string(), "must be less than 60");
+    assert_eq!(err.message().inclusive().to_string(), "must be at most 59");
+}
+
+#[test]
+fn greater_than() {
+    let range = Bounds { lower: Bound::Excluded(0.0), upper: Bound::Unbounded };
+    let err = 0.0.check_range(range).unwrap_err();
+    assert_eq!(err.message().to_string(), "must be greater than 0");
+}
+
+#[test]
+fn open_inclusive() {
+    let err = 10.check_range(Bounds::open(0, 10)).unwrap_err();
+    assert_eq!(err.message().to_string(), "must be greater than 0 and less than 10");
+    assert_eq!(err.message().inclusive().to_string(), "must be between 1 and 9");
+}
+
+#[test]
+fn inclusive_crossed() {
+    let err = 5.check_range(Bounds::open(0, 1)).unwrap_err();
+    assert_eq!(err.message().to_string(), "must be greater than 0 and less than 1");
+    assert_eq!(err.message().inclusive().to_string(), "cannot be any value, as the range is empty");
+}
+
+#[test]
+fn inclusive_crossed_in_set() {
+    let set: RangeSet<i32> = vec![ Bounds::open(0, 1), Bounds::at_least(10) ].into_iter().collect();
+    let err = set.check(5).unwrap_err();
+    assert_eq!(err.message().inclusive().to_string(), "must be at least 10");
+}
+
+#[test]
+fn inclusive_at_limit() {
+    let range = Bounds { lower: Bound::Excluded(u8::MAX), upper: Bound::Unbounded };
+    let err = 3_u8.check_range(range).unwrap_err();
+    assert_eq!(err.message().inclusive().to_string(), "must be greater than 255");
+}
+
+#[test]
+fn uses_display() {
+    let err = "zebra".check_range("a" .. "n").unwrap_err();
+    assert_eq!(err.message().to_string(), "must be at least a and less than n");
+}
+
+#[test]
+fn with_path() {
+    let err = 61.check_range_named("minute", 0..60).unwrap_err().with_path("alarm");
+    assert_eq!(err.message().to_string(), "alarm.minute: must be at least 0 and less than 60");
+}
+
+#[test]
+fn any_of() {
+    let set: RangeSet<i32> = vec![ Bounds::closed(1, 5), Bounds::at_least(10) ].into_iter().collect();
+    let err = set.check(7).unwrap_err();
+    assert_eq!(err.message().to_string(), "must be between 1 and 5, or at least 10");
+    assert_eq!(err.message_in(&GermanCatalog).to_string(), "muss zwischen 1 und 5 liegen oder mindestens 10 sein");
+}
+
+#[test]
+fn empty_set() {
+    let set: RangeSet<i32> = RangeSet::new();
+    let err = set.check(7).unwrap_err();
+    assert_eq!(err.message().to_string(), "cannot be any value, as the range is empty");
+}
+
+#[test]
+fn inverted() {
+    #[allow(clippy::reversed_empty_ranges)]
+    let err = 7.check_range(10 .. 5).unwrap_err();
+    assert_eq!(err.message_in(&EnglishCatalog).to_string(), "cannot be any value, as the range is empty");
+}
+
+#[test]
+fn incomparable() {
+    let err = f64::NAN.check_range(0.0 .. 1.0).unwrap_err();
+    assert_eq!(err.message().to_string(), "must be a comparable value");
+    assert_eq!(err.message_in(&GermanCatalog).to_string(), "muss ein vergleichbarer Wert sein");
+}
+
+
+#[test]
+fn singleton() {
+    let err = 8.check_range(7 ..= 7).unwrap_err();
+    assert_eq!(err.message().to_string(), "must be 7");
+    assert_eq!(err.message_in(&GermanCatalog).to_string(), "muss 7 sein");
+}
+
+#[test]
+fn singleton_inclusive() {
+    let err = 8.check_range(7 .. 8).unwrap_err();
+    assert_eq!(err.message().to_string(), "must be at least 7 and less than 8");
+    assert_eq!(err.message().inclusive().to_string(), "must be 7");
+}
+
+#[test]
+fn singleton_in_set() {
+    let set: RangeSet<i32> = vec![ Bounds::closed(1, 3), Bounds::closed(7, 7) ].into_iter().collect();
+    let err = 5.check_range(&set).unwrap_err();
+    assert_eq!(err.message().to_string(), "must be between 1 and 3, or 7");
+    assert_eq!(err.message_in(&GermanCatalog).to_string(), "muss zwischen 1 und 3 liegen oder 7 sein");
+}
+
+
+#[test]
+fn german() {
+    let check = |value: i32, range: Bounds<i32>| value.check_range(range).unwrap_err().message_in(&GermanCatalog).to_string();
+
+    assert_eq!(check(0, Bounds::closed(1, 9998)), "muss zwischen 1 und 9998 liegen");
+    assert_eq!(check(-1, Bounds::at_least(0)), "muss mindestens 0 sein");
+    assert_eq!(check(60, Bounds { lower: Bound::Unbounded, upper: Bound::Excluded(60) }), "muss kleiner als 60 sein");
+    assert_eq!(check(6, Bounds::at_most(5)), "muss höchstens 5 sein");
+    assert_eq!(check(1, Bounds::open(1, 5)), "muss größer als 1 und kleiner als 5 sein");
+}
+
+#[test]
+fn german_inclusive() {
+    let err = 60.check_range(0 .. 60).unwrap_err();
+    assert_eq!(err.message_in(&GermanCatalog).inclusive().to_string(), "muss zwischen 0 und 59 liegen");
+}
+
+
+#[test]
+fn discrete_chars() {
+    assert_eq!('a'.successor(), Some('b'));
+    assert_eq!('\u{D7FF}'.successor(), Some('\u{E000}'));
+    assert_eq!('\u{E000}'.predecessor(), Some('\u{D7FF}'));
+    assert_eq!(char::MAX.successor(), None);
+    assert_eq!('\0'.predecessor(), None);
+}
+
+#[test]
+fn discrete_integers() {
+    assert_eq!(5_u8.successor(), Some(6));
+    assert_eq!(u8::MAX.successor(), None);
+    assert_eq!(i64::MIN.predecessor(), None);
+}
+
+#[test]
+fn discrete_floats() {
+    assert_eq!(0.0_f64.successor(), Some(f64::from_bits(1)));
+    assert_eq!(1.0_f32.predecessor(), Some(1.0 - f32::EPSILON / 2.0));
+    assert_eq!(f64::INFINITY.successor(), None);
+    assert_eq!(f64::NAN.predecessor(), None);
+}
@@ -0,0 +1,128 @@
+extern crate range_check;
+use range_check::{Bounds, CheckMixed, ErrorKind, ExactCmp, Violation};
+
+
+// Signed and unsigned
+
+#[test]
+fn negative_below_unsigned() {
+    let err = (-1_i32).check_range_mixed(0_u64 .. 10).unwrap_err();
+    assert_eq!(err.violation(), Violation::BelowLower);
+    assert_eq!(err.allowed_range, Bounds::half_open(0_u64, 10_u64));
+    assert_eq!(err.outside_value, -1_i32);
+}
+
+#[test]
+fn large_unsigned_above_signed() {
+    assert_eq!(u64::MAX.check_range_mixed(..= i64::MAX).unwrap_err().violation(), Violation::AboveUpper);
+    assert_eq!(u128::MAX.check_range_mixed(i128::MIN ..).ok(), Some(u128::MAX));
+    assert_eq!(i128::MIN.check_range_mixed(0_u128 ..).unwrap_err().violation(), Violation::BelowLower);
+}
+
+#[test]
+fn matches_widened_comparison() {
+    for value in i8::MIN ..= i8::MAX {
+        for upper in 0_u8 ..= u8::MAX {
+            let expected = (value as i16) < (upper as i16);
+            assert_eq!(value.check_range_mixed(.. upper).is_ok(), expected, "{} < {}", value, upper);
+        }
+    }
+}
+
+
+// Integers and floats
+
+#[test]
+fn int_against_float_bounds() {
+    assert!(7_u8.check_range_mixed(0.5_f32 .. 7.5).is_ok());
+    assert!(8_u8.check_range_mixed(0.5_f32 .. 7.5).is_err());
+    assert!(0_u8.check_range_mixed(0.5_f32 .. 7.5).is_err());
+    assert!((-3_i32).check_range_mixed(-3.0_f64 ..= -2.5).is_ok());
+    assert!((-3_i32).check_range_mixed(-2.999_f64 ..).is_err());
+}
+
+#[test]
+fn int_beyond_float_precision() {
+    let float = 9007199254740992.0_f64;  // 2^53
+    let int = 9007199254740993_i64;      // 2^53 + 1, which casts to 2^53
+
+    assert_eq!(int as f64, float);
+    assert!(int.check_range_mixed(..= float).is_err());
+    assert!(int.check_range_mixed(.. float + 2.0).is_ok());
+}
+
+#[test]
+fn float_against_int_bounds() {
+    assert!(0.5_f64.check_range_mixed(0_i32 .. 1).is_ok());
+    assert!(1.0_f64.check_range_mixed(0_i32 .. 1).is_err());
+    assert!((-0.0_f64).check_range_mixed(0_u8 ..= 0).is_ok());
+    assert!(1e30_f32.check_range_mixed(.. u128::MAX).is_ok());
+    assert!(1e39_f64.check_range_mixed(.. u128::MAX).is_err());
+}
+
+#[test]
+fn float_just_beyond_u64() {
+    let power = 18446744073709551616.0_f64;  // 2^64
+    assert_eq!(u64::MAX as f64, power);
+    assert!(power.check_range_mixed(..= u64::MAX).is_err());
+    assert!(u64::MAX.check_range_mixed(.. power).is_ok());
+}
+
+#[test]
+fn infinities() {
+    assert_eq!(f64::INFINITY.check_range_mixed(..= u128::MAX).unwrap_err().violation(), Violation::AboveUpper);
+    assert!(i128::MIN.check_range_mixed(f32::NEG_INFINITY ..).is_ok());
+}
+
+
+// Floats of different sizes
+
+#[test]
+fn f32_against_f64() {
+    let tenth = 0.1_f32;
+    assert!(tenth.check_range_mixed(0.1_f64 ..).is_ok());
+    assert!(tenth.check_range_mixed(.. 0.1_f64).is_err());
+}
+
+
+// Errors
+
+#[test]
+fn nan() {
+    let err = f64::NAN.check_range_mixed(0_i32 ..).unwrap_err();
+    assert_eq!(err.kind, ErrorKind::Incomparable);
+    assert_eq!(err.violation(), Violation::Incomparable);
+
+    assert_eq!(5_i32.check_range_mixed(0.0 .. f64::NAN).unwrap_err().kind, ErrorKind::Incomparable);
+}
+
+#[test]
+fn unbounded_nan() {
+    assert_eq!(f32::NAN.check_range_mixed::<u8, _>(..).unwrap_err().kind, ErrorKind::Incomparable);
+}
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn empty_ranges() {
+    assert_eq!(4_u8.check_range_mixed(5_i64 .. 3).unwrap_err().kind, ErrorKind::InvertedRange);
+    assert_eq!(4_u8.check_range_mixed(4_i64 .. 4).unwrap_err().kind, ErrorKind::EmptyRange);
+}
+
+#[test]
+fn display() {
+    assert_eq!(3_000_000_000_u64.check_range_mixed(0 ..= i32::MAX).unwrap_err().to_string(),
+               "value (3000000000) above range (0..=2147483647)");
+    assert_eq!((-1_i8).check_range_mixed(0.0_f32 .. 1.0).unwrap_err().to_string(),
+               "value (-1) below range (0.0..1.0)");
+}
+
+#[test]
+fn generic_over_value() {
+    fn below_limit<T: ExactCmp<i32> + Copy>(value: T) -> bool {
+        value.check_range_mixed(.. 100_i32).is_ok()
+    }
+
+    assert!(below_limit(99_u64));
+    assert!(! below_limit(100.0_f64));
+    assert!(below_limit(-1e30_f32));
+}
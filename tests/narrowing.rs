@@ -0,0 +1,138 @@
+extern crate range_check;
+use range_check::{Bounds, CheckInto, ErrorKind, OutOfRangeError, RangeOf, Violation};
+
+use std::convert::TryFrom;
+
+
+// Integers to integers
+
+#[test]
+fn narrow_in_range() {
+    assert_eq!(127_i64.check_into::<i8>(), Ok(127_i8));
+    assert_eq!((-128_i64).check_into::<i8>(), Ok(-128_i8));
+}
+
+#[test]
+fn narrow_out_of_range() {
+    let err = 128_i64.check_into::<i8>().unwrap_err();
+    assert_eq!(err.allowed_range, Bounds::closed(-128, 127));
+    assert_eq!(err.violation(), Violation::AboveUpper);
+}
+
+#[test]
+fn signed_to_unsigned() {
+    let err = (-1_i32).check_into::<u64>().unwrap_err();
+    assert_eq!(err.allowed_range, Bounds::closed(0, i32::MAX));
+    assert_eq!(err.to_string(), "value (-1) below range (0..=2147483647)");
+}
+
+#[test]
+fn unsigned_to_signed() {
+    assert_eq!(u128::MAX.check_into::<i128>().unwrap_err().allowed_range,
+               Bounds::closed(0, i128::MAX as u128));
+}
+
+#[test]
+fn widening_always_works() {
+    assert_eq!(i8::MIN.check_into::<i128>(), Ok(-128));
+    assert_eq!(u64::MAX.check_into::<u128>(), Ok(u64::MAX as u128));
+}
+
+#[test]
+fn matches_try_from() {
+    for n in i16::MIN ..= i16::MAX {
+        assert_eq!(n.check_into::<i8>().ok(), i8::try_from(n).ok());
+        assert_eq!(n.check_into::<u8>().ok(), u8::try_from(n).ok());
+    }
+}
+
+
+// Floats to integers
+
+#[test]
+fn float_drops_fraction() {
+    assert_eq!(255.9_f32.check_into::<u8>(), Err(range_check::OutOfRangeError::new(Bounds::closed(0.0, 255.0), 255.9, ErrorKind::Outside)));
+    assert_eq!(254.9_f32.check_into::<u8>(), Ok(254));
+    assert_eq!((-0.0_f64).check_into::<u8>(), Ok(0));
+}
+
+#[test]
+fn float_below_unsigned() {
+    assert_eq!((-0.5_f64).check_into::<u32>().unwrap_err().violation(), Violation::BelowLower);
+}
+
+#[test]
+fn float_to_i64_exact_bounds() {
+    let below_power = f64::from_bits((2.0_f64.powi(63)).to_bits() - 1);
+
+    assert_eq!((2.0_f64.powi(63)).check_into::<i64>().unwrap_err().allowed_range,
+               Bounds::closed(-(2.0_f64.powi(63)), below_power));
+    assert_eq!(below_power.check_into::<i64>(), Ok(below_power as i64));
+    assert_eq!((-(2.0_f64.powi(63))).check_into::<i64>(), Ok(i64::MIN));
+}
+
+#[test]
+fn float_to_u128() {
+    assert_eq!(f32::MAX.check_into::<u128>(), Ok(f32::MAX as u128));
+    assert!(f32::INFINITY.check_into::<u128>().is_err());
+}
+
+#[test]
+fn float_to_small_int_exact_bounds() {
+    assert_eq!(32767.0_f32.check_into::<i16>(), Ok(i16::MAX));
+    assert!(32767.5_f32.check_into::<i16>().is_err());
+}
+
+#[test]
+fn nan_to_int() {
+    assert_eq!(f64::NAN.check_into::<i32>().unwrap_err().kind, ErrorKind::Incomparable);
+}
+
+
+// Integers to floats
+
+#[test]
+fn int_to_float() {
+    assert_eq!(i64::MAX.check_into::<f64>(), Ok(i64::MAX as f64));
+    assert_eq!(u8::MAX.check_into::<f32>(), Ok(255.0));
+}
+
+#[test]
+fn u128_beyond_f32() {
+    let err = u128::MAX.check_into::<f32>().unwrap_err();
+    assert_eq!(err.allowed_range, Bounds::closed(0, f32::MAX as u128));
+    assert_eq!((f32::MAX as u128).check_into::<f32>(), Ok(f32::MAX));
+}
+
+
+// Floats to floats
+
+#[test]
+fn f64_to_f32() {
+    assert_eq!(1.5_f64.check_into::<f32>(), Ok(1.5_f32));
+    assert_eq!(1e39_f64.check_into::<f32>().unwrap_err().allowed_range,
+               Bounds::closed(f32::MIN as f64, f32::MAX as f64));
+}
+
+#[test]
+fn f32_to_f64() {
+    assert_eq!(f32::MAX.check_into::<f64>(), Ok(f32::MAX as f64));
+    assert!(f32::NEG_INFINITY.check_into::<f64>().is_err());
+}
+
+#[test]
+fn infinity_to_float() {
+    assert_eq!(f64::INFINITY.check_into::<f64>().unwrap_err().violation(), Violation::AboveUpper);
+}
+
+#[test]
+fn generic_over_source() {
+    fn to_u16<T: RangeOf<u16> + PartialOrd + Copy>(value: T) -> Result<u16, OutOfRangeError<T>> {
+        value.check_into()
+    }
+
+    assert_eq!(to_u16(65535_u32), Ok(65535));
+    assert_eq!(to_u16(70000_i64).unwrap_err().violation(), Violation::AboveUpper);
+    assert_eq!(to_u16(12.75_f32), Ok(12));
+    assert!(to_u16(-0.5_f32).is_err());
+}
@@ -0,0 +1,148 @@
+extern crate range_check;
+use range_check::{Bounds, ParseBoundsError, ParseErrorKind};
+
+extern crate quickcheck;
+use quickcheck::quickcheck;
+
+use std::error::Error;
+use std::ops::Bound;
+
+
+fn parse(input: &str) -> Result<Bounds<i32>, ParseBoundsError<std::num::ParseIntError>> {
+    input.parse()
+}
+
+fn bound<T>(kind: u8, value: T) -> Bound<T> {
+    match kind % 3 {
+        0  => Bound::Unbounded,
+        1  => Bound::Included(value),
+        _  => Bound::Excluded(value),
+    }
+}
+
+
+#[test]
+fn half_open() {
+    assert_eq!(parse("1..9999"), Ok(Bounds::half_open(1, 9999)));
+}
+
+#[test]
+fn closed() {
+    assert_eq!(parse("1..=5"), Ok(Bounds::closed(1, 5)));
+}
+
+#[test]
+fn open() {
+    assert_eq!(parse("1<..5"), Ok(Bounds::open(1, 5)));
+}
+
+#[test]
+fn only_upper() {
+    assert_eq!(parse("..10"), Ok(Bounds { lower: Bound::Unbounded, upper: Bound::Excluded(10) }));
+    assert_eq!(parse("..=10"), Ok(Bounds::at_most(10)));
+}
+
+#[test]
+fn only_lower() {
+    assert_eq!(parse("10.."), Ok(Bounds::at_least(10)));
+}
+
+#[test]
+fn unbounded() {
+    assert_eq!(parse(".."), Ok(Bounds::unbounded()));
+}
+
+#[test]
+fn negatives() {
+    assert_eq!(parse("-10..-1"), Ok(Bounds::half_open(-10, -1)));
+}
+
+#[test]
+fn whitespace() {
+    assert_eq!(parse("  1 ..= 5 "), Ok(Bounds::closed(1, 5)));
+}
+
+#[test]
+fn floats() {
+    assert_eq!("0.5..1.5".parse(), Ok(Bounds::half_open(0.5, 1.5)));
+}
+
+
+#[test]
+fn no_dots() {
+    let err = parse("15").unwrap_err();
+    assert_eq!(err.offset, 2);
+    assert_eq!(err.kind, ParseErrorKind::MissingDots);
+    assert_eq!(err.to_string(), "expected `..` at byte 2");
+}
+
+#[test]
+fn bad_lower() {
+    let err = parse("one..5").unwrap_err();
+    assert_eq!(err.offset, 0);
+    assert!(matches!(err.kind, ParseErrorKind::InvalidValue(_)));
+    assert!(err.source().is_some());
+}
+
+#[test]
+fn bad_upper() {
+    let err = parse("1 ..=  five").unwrap_err();
+    assert_eq!(err.offset, 7);
+    assert_eq!(err.to_string(), "invalid value at byte 7: invalid digit found in string");
+}
+
+#[test]
+fn extra_dots() {
+    let err = parse("1..5..9").unwrap_err();
+    assert_eq!(err.offset, 3);
+}
+
+#[test]
+fn missing_upper() {
+    let err = parse("1..=").unwrap_err();
+    assert_eq!(err.offset, 4);
+    assert_eq!(err.kind, ParseErrorKind::MissingValue);
+    assert!(err.source().is_none());
+}
+
+#[test]
+fn missing_lower() {
+    let err = parse("<..5").unwrap_err();
+    assert_eq!(err.offset, 0);
+    assert_eq!(err.kind, ParseErrorKind::MissingValue);
+}
+
+
+#[test]
+fn round_trip_integers() {
+    fn prop(lower_kind: u8, lower: i64, upper_kind: u8, upper: i64) -> bool {
+        let bounds = Bounds { lower: bound(lower_kind, lower), upper: bound(upper_kind, upper) };
+        bounds.to_string().parse() == Ok(bounds)
+    }
+
+    quickcheck(prop as fn(u8, i64, u8, i64) -> bool);
+}
+
+#[test]
+fn round_trip_floats() {
+    fn prop(lower_kind: u8, lower: f64, upper_kind: u8, upper: f64) -> bool {
+        if lower.is_nan() || upper.is_nan() {
+            return true;
+        }
+
+        let bounds = Bounds { lower: bound(lower_kind, lower), upper: bound(upper_kind, upper) };
+        bounds.to_string().parse() == Ok(bounds)
+    }
+
+    quickcheck(prop as fn(u8, f64, u8, f64) -> bool);
+}
+
+#[test]
+fn round_trip_small_integers() {
+    fn prop(lower_kind: u8, lower: u8, upper_kind: u8, upper: u8) -> bool {
+        let bounds = Bounds { lower: bound(lower_kind, lower), upper: bound(upper_kind, upper) };
+        bounds.to_string().parse() == Ok(bounds)
+    }
+
+    quickcheck(prop as fn(u8, u8, u8, u8) -> bool);
+}
@@ -0,0 +1,101 @@
+extern crate range_check;
+use range_check::{Check, FieldError, OutOfRangeError, Validator};
+
+
+#[test]
+fn no_path_by_default() {
+    let err = 60.check_range(0..60).unwrap_err();
+    assert_eq!(err.path, None);
+    assert_eq!(err.to_string(), "value (60) above range (0..60)");
+}
+
+#[test]
+fn named() {
+    let err = 60.check_range_named("minute", 0..60).unwrap_err();
+    assert_eq!(err.path.as_ref().unwrap(), "minute");
+}
+
+#[test]
+fn named_passes() {
+    assert_eq!(30.check_range_named("minute", 0..60), Ok(30));
+}
+
+#[test]
+fn prefixed_fields() {
+    let err = 25.check_range_named("hour", 0..24).unwrap_err().with_path("alarm").with_path("clock");
+    assert_eq!(err.path.as_ref().unwrap(), "clock.alarm.hour");
+}
+
+#[test]
+fn prefixed_index() {
+    let err = 25.check_range_named("hour", 0..24).unwrap_err().with_path("[3]").with_path("alarms");
+    assert_eq!(err.path.as_ref().unwrap(), "alarms[3].hour");
+}
+
+#[test]
+fn path_without_name() {
+    let err = 25.check_range(0..24).unwrap_err().with_path("[0]");
+    assert_eq!(err.to_string(), "[0]: value (25) above range (0..24)");
+}
+
+#[test]
+fn empty_segments_skipped() {
+    let err = 25.check_range_named("hour", 0..24).unwrap_err().with_path("").with_path("clock");
+    assert_eq!(err.path.as_ref().unwrap(), "clock.hour");
+
+    let err = 25.check_range_named("", 0..24).unwrap_err().with_path("clock");
+    assert_eq!(err.path.as_ref().unwrap(), "clock");
+}
+
+#[test]
+fn empty_path_not_displayed() {
+    let err = FieldError::new("", 25.check_range(0..24).unwrap_err());
+    assert_eq!(err.field(), "");
+    assert_eq!(err.to_string(), "value (25) above range (0..24)");
+    assert_eq!(err.error.message().to_string(), "must be at least 0 and less than 24");
+}
+
+#[test]
+fn kept_when_generified() {
+    let err: OutOfRangeError<i64> = 300_i16.check_range_named("count", 0..256).unwrap_err().generify();
+    assert_eq!(err.path.as_ref().unwrap(), "count");
+}
+
+#[test]
+fn bubbling_up() {
+    struct Alarm { minute: i8 }
+    struct Clock { alarms: Vec<Alarm> }
+
+    fn check_alarm(alarm: &Alarm) -> Result<(), OutOfRangeError<i8>> {
+        let _ = alarm.minute.check_range_named("minute", 0..60)?;
+        Ok(())
+    }
+
+    fn check_clock(clock: &Clock) -> Result<(), OutOfRangeError<i8>> {
+        for (i, alarm) in clock.alarms.iter().enumerate() {
+            check_alarm(alarm).map_err(|e| e.with_path(&format!("alarms[{}]", i)))?;
+        }
+        Ok(())
+    }
+
+    let clock = Clock { alarms: vec![ Alarm { minute: 0 }, Alarm { minute: 15 }, Alarm { minute: 75 } ] };
+    let err = check_clock(&clock).map_err(|e| e.with_path("clock")).unwrap_err();
+    assert_eq!(err.to_string(), "clock.alarms[2].minute: value (75) above range (0..60)");
+}
+
+#[test]
+fn set_by_validator() {
+    let mut validator = Validator::<i32>::new();
+    validator.check("hour", 25, 0..24);
+
+    let failure = validator.finish().unwrap_err().into_failures().remove(0);
+    assert_eq!(failure.error.path.as_ref().unwrap(), "hour");
+    assert_eq!(failure.to_string(), "hour: value (25) above range (0..24)");
+}
+
+#[test]
+fn field_error_of_named_error() {
+    let err = FieldError::new("alarm", 75.check_range_named("minute", 0..60).unwrap_err());
+    assert_eq!(err.field(), "alarm.minute");
+    assert_eq!(err.to_string(), "alarm.minute: value (75) above range (0..60)");
+}
@@ -0,0 +1,137 @@
+extern crate range_check;
+use range_check::{Bounds, Check, ErrorKind, RangeSet, Violation};
+
+use std::ops::Bound::*;
+
+
+fn set(ranges: Vec<Bounds<i32>>) -> RangeSet<i32> {
+    ranges.into_iter().collect()
+}
+
+
+#[test]
+fn sorted() {
+    let set = set(vec![ Bounds::closed(7, 9), Bounds::closed(1, 3) ]);
+    assert_eq!(set.as_slice(), &[ Bounds::closed(1, 3), Bounds::closed(7, 9) ]);
+}
+
+#[test]
+fn overlapping_merged() {
+    let set = set(vec![ Bounds::closed(1, 5), Bounds::closed(3, 9) ]);
+    assert_eq!(set.as_slice(), &[ Bounds::closed(1, 9) ]);
+}
+
+#[test]
+fn contained_merged() {
+    let set = set(vec![ Bounds::closed(1, 9), Bounds::closed(3, 5) ]);
+    assert_eq!(set.as_slice(), &[ Bounds::closed(1, 9) ]);
+}
+
+#[test]
+fn adjacent_merged() {
+    let set = set(vec![ Bounds::half_open(1, 5), Bounds::closed(5, 9) ]);
+    assert_eq!(set.as_slice(), &[ Bounds::closed(1, 9) ]);
+}
+
+#[test]
+fn adjacent_excluded_merged() {
+    let set = set(vec![ Bounds::closed(1, 5), Bounds::open(5, 9) ]);
+    assert_eq!(set.as_slice(), &[ Bounds::half_open(1, 9) ]);
+}
+
+#[test]
+fn both_excluded_not_merged() {
+    let set = set(vec![ Bounds::half_open(1, 5), Bounds::open(5, 9) ]);
+    assert_eq!(set.as_slice().len(), 2);
+    assert!(!set.contains(&5));
+}
+
+#[test]
+fn unbounded_swallows() {
+    let set = set(vec![ Bounds::closed(1, 5), Bounds::at_least(3), Bounds::closed(20, 30) ]);
+    assert_eq!(set.as_slice(), &[ Bounds::at_least(1) ]);
+}
+
+#[test]
+fn empty_discarded() {
+    let set = set(vec![ Bounds::half_open(5, 5), Bounds::closed(9, 1) ]);
+    assert!(set.is_empty());
+}
+
+#[test]
+fn insert() {
+    let mut set = RangeSet::new();
+    set.insert(Bounds::closed(1, 3));
+    set.insert(Bounds::closed(7, 9));
+    set.insert(Bounds::closed(3, 7));
+    assert_eq!(set.as_slice(), &[ Bounds::closed(1, 9) ]);
+}
+
+#[test]
+fn remove() {
+    let mut set = set(vec![ Bounds::closed(1, 3), Bounds::closed(5, 9) ]);
+    set.remove(&Bounds::closed(2, 6));
+    assert_eq!(set.as_slice(), &[ Bounds::half_open(1, 2), Bounds { lower: Excluded(6), upper: Included(9) } ]);
+}
+
+#[test]
+fn union() {
+    let a = set(vec![ Bounds::closed(1, 3), Bounds::closed(10, 12) ]);
+    let b = set(vec![ Bounds::closed(2, 5) ]);
+    assert_eq!(a.union(&b).as_slice(), &[ Bounds::closed(1, 5), Bounds::closed(10, 12) ]);
+}
+
+#[test]
+fn intersection() {
+    let a = set(vec![ Bounds::closed(1, 5), Bounds::closed(10, 15) ]);
+    let b = set(vec![ Bounds::closed(4, 11) ]);
+    assert_eq!(a.intersection(&b).as_slice(), &[ Bounds::closed(4, 5), Bounds::closed(10, 11) ]);
+}
+
+#[test]
+fn difference() {
+    let a = set(vec![ Bounds::closed(1, 15) ]);
+    let b = set(vec![ Bounds::half_open(4, 6), Bounds::open(10, 20) ]);
+    assert_eq!(a.difference(&b).as_slice(), &[ Bounds::half_open(1, 4), Bounds::closed(6, 10) ]);
+}
+
+#[test]
+fn hull() {
+    let a = set(vec![ Bounds::closed(1, 5), Bounds::half_open(10, 15) ]);
+    assert_eq!(a.hull(), Some(Bounds::half_open(1, 15)));
+    assert_eq!(RangeSet::<i32>::new().hull(), None);
+}
+
+
+#[test]
+fn check_ok() {
+    let ports = set(vec![ Bounds::half_open(1, 1024), Bounds::half_open(8000, 9000) ]);
+    assert_eq!(80.check_range(&ports), Ok(80));
+    assert_eq!(8080.check_range(&ports), Ok(8080));
+    assert_eq!(ports.check(80), Ok(80));
+}
+
+#[test]
+fn check_gap() {
+    let ports = set(vec![ Bounds::half_open(1, 1024), Bounds::half_open(8000, 9000) ]);
+    let err = 5000.check_range(&ports).unwrap_err();
+    assert_eq!(ports.check(5000), Err(err.clone()));
+
+    assert_eq!(err.allowed_range, Bounds::half_open(1, 9000));
+    assert_eq!(err.kind, ErrorKind::OutsideSet(ports.as_slice().to_vec()));
+    assert_eq!(err.to_string(), "value (5000) between ranges (1..1024, 8000..9000)");
+}
+
+#[test]
+fn check_empty_set() {
+    let err = RangeSet::new().check(5).unwrap_err();
+    assert_eq!(err.to_string(), "value (5) outside of empty range set");
+    assert_eq!(err.violation(), Violation::EmptySet);
+}
+
+#[test]
+fn check_generify() {
+    let ports: RangeSet<u16> = vec![ Bounds::half_open(1, 1024) ].into_iter().collect();
+    let err = 2000_u16.check_range(ports).unwrap_err().generify::<u32>();
+    assert_eq!(err.kind, ErrorKind::OutsideSet(vec![ Bounds::half_open(1, 1024) ]));
+}
@@ -0,0 +1,109 @@
+#![cfg(feature = "serde")]
+
+extern crate range_check;
+use range_check::{Bounds, Check, ErrorKind, OutOfRangeError, RangeSet};
+
+extern crate serde;
+use serde::{Deserialize, Serialize};
+
+extern crate serde_json;
+use serde_json::json;
+
+use std::ops::Bound;
+
+
+#[test]
+fn half_open() {
+    let json = serde_json::to_value(Bounds::half_open(0, 24)).unwrap();
+    assert_eq!(json, json!({ "lower": { "value": 0, "inclusive": true },
+                             "upper": { "value": 24, "inclusive": false } }));
+}
+
+#[test]
+fn unbounded_ends() {
+    let json = serde_json::to_value(Bounds::at_least(5)).unwrap();
+    assert_eq!(json, json!({ "lower": { "value": 5, "inclusive": true }, "upper": null }));
+}
+
+#[test]
+fn round_trip() {
+    let ranges = vec![
+        Bounds::closed(1, 5),
+        Bounds::open(1, 5),
+        Bounds { lower: Bound::Excluded(1), upper: Bound::Unbounded },
+        Bounds::at_most(5),
+        Bounds::unbounded(),
+    ];
+
+    for range in ranges {
+        let json = serde_json::to_string(&range).unwrap();
+        assert_eq!(serde_json::from_str::<Bounds<i32>>(&json).unwrap(), range);
+    }
+}
+
+#[test]
+fn missing_end_is_unbounded() {
+    let range = serde_json::from_str::<Bounds<i32>>(r#"{ "lower": { "value": 3, "inclusive": false } }"#).unwrap();
+    assert_eq!(range, Bounds { lower: Bound::Excluded(3), upper: Bound::Unbounded });
+}
+
+#[test]
+fn missing_inclusive_flag() {
+    assert!(serde_json::from_str::<Bounds<i32>>(r#"{ "lower": { "value": 3 }, "upper": null }"#).is_err());
+}
+
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct Setting {
+    #[serde(with = "range_check::bounds_as_string")]
+    hours: Bounds<u8>,
+}
+
+#[test]
+fn as_string() {
+    let setting = Setting { hours: Bounds { lower: Bound::Excluded(0), upper: Bound::Included(12) } };
+    let json = serde_json::to_value(&setting).unwrap();
+    assert_eq!(json, json!({ "hours": "0<..=12" }));
+    assert_eq!(serde_json::from_value::<Setting>(json).unwrap(), setting);
+}
+
+#[test]
+fn as_string_invalid() {
+    let err = serde_json::from_str::<Setting>(r#"{ "hours": "0..x" }"#).unwrap_err();
+    assert!(err.to_string().starts_with("invalid value at byte 3"));
+}
+
+
+#[test]
+fn error() {
+    let err = 61.check_range_named("minute", 0..60).unwrap_err();
+    let json = serde_json::to_value(&err).unwrap();
+    assert_eq!(json, json!({
+        "allowed_range": { "lower": { "value": 0, "inclusive": true },
+                           "upper": { "value": 60, "inclusive": false } },
+        "outside_value": 61,
+        "kind": "outside",
+        "path": "minute",
+    }));
+
+    assert_eq!(serde_json::from_value::<OutOfRangeError<i32>>(json).unwrap(), err);
+}
+
+#[test]
+fn error_with_set() {
+    let set: RangeSet<i32> = vec![ Bounds::half_open(0, 5), Bounds::at_least(10) ].into_iter().collect();
+    let err = set.check(7).unwrap_err();
+
+    let json = serde_json::to_string(&err).unwrap();
+    let back: OutOfRangeError<i32> = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, err);
+    assert!(matches!(back.kind, ErrorKind::OutsideSet(ref ranges) if ranges.len() == 2));
+    assert_eq!(back.to_string(), "value (7) between ranges (0..5, 10..)");
+}
+
+#[test]
+fn error_floats() {
+    let err = 1.5_f64.check_range(0.0 ..= 1.0).unwrap_err();
+    let json = serde_json::to_string(&err).unwrap();
+    assert_eq!(serde_json::from_str::<OutOfRangeError<f64>>(&json).unwrap(), err);
+}
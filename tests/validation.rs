@@ -0,0 +1,96 @@
+extern crate range_check;
+use range_check::{Bounds, Check, FieldError, ValidationReport, Validator, Violation};
+
+
+#[test]
+fn all_valid() {
+    let mut validator = Validator::<i32>::new();
+    validator.check("a", 1, 0..10)
+             .check("b", 2, 0..10);
+
+    assert!(validator.is_valid());
+    assert_eq!(validator.finish(), Ok(()));
+}
+
+#[test]
+fn keeps_every_failure_in_order() {
+    let mut validator = Validator::<i32>::new();
+    validator.check("a", 10, 0..10)
+             .check("b", 5, 0..10)
+             .check("c", -1, 0..10);
+
+    let report = validator.finish().unwrap_err();
+    let fields: Vec<_> = report.iter().map(|f| f.field()).collect();
+    assert_eq!(fields, vec![ "a", "c" ]);
+
+    let violations: Vec<_> = report.iter().map(|f| f.error.violation()).collect();
+    assert_eq!(violations, vec![ Violation::AboveUpper, Violation::BelowLower ]);
+}
+
+#[test]
+fn mixed_types() {
+    let mut validator = Validator::<f64>::new();
+    validator.check("ratio", 1.5_f32, 0.0..=1.0)
+             .check("count", 200_u8, 0..=100)
+             .check("offset", -40_i32, -30..30);
+
+    let report = validator.finish().unwrap_err();
+    assert_eq!(report.len(), 3);
+    assert_eq!(report.iter().last().unwrap().error.allowed_range, Bounds::half_open(-30.0, 30.0));
+}
+
+#[test]
+fn nested_paths() {
+    let mut validator = Validator::<i32>::new();
+    validator.nested("clock", |v| {
+        v.check("hour", 24, 0..24);
+        v.nested("alarms", |v| {
+            v.nested("[2]", |v| {
+                v.check("minute", 61, 0..60);
+            });
+        });
+    });
+    validator.check("volume", 12, 0..=11);
+
+    let fields: Vec<_> = validator.finish().unwrap_err().into_iter().map(|f| f.field().to_owned()).collect();
+    assert_eq!(fields, vec![ "clock.hour", "clock.alarms[2].minute", "volume" ]);
+}
+
+#[test]
+fn records_field_errors() {
+    let inner = 70.check_range(0..60).map_err(|e| FieldError::new("minute", e));
+
+    let mut validator = Validator::<i64>::new();
+    validator.record("alarm", inner);
+    validator.record("ok", Ok::<_, FieldError<i32>>(()));
+
+    let report = validator.finish().unwrap_err();
+    assert_eq!(report.len(), 1);
+    assert_eq!(report.iter().next().unwrap().field(), "alarm.minute");
+}
+
+#[test]
+fn display_single() {
+    let mut validator = Validator::<i32>::new();
+    validator.check("minute", 60, 0..60);
+
+    assert_eq!(validator.finish().unwrap_err().to_string(),
+               "minute: value (60) above range (0..60)");
+}
+
+#[test]
+fn display_several() {
+    let mut validator = Validator::<i32>::new();
+    validator.check("hour", -1, 0..24)
+             .check("minute", 60, 0..60);
+
+    assert_eq!(validator.finish().unwrap_err().to_string(),
+               "2 values out of range: hour: value (-1) below range (0..24); minute: value (60) above range (0..60)");
+}
+
+#[test]
+fn from_field_error() {
+    let error = FieldError::new("x", 5.check_range(0..5).unwrap_err());
+    let report: ValidationReport<i32> = error.clone().into();
+    assert_eq!(report.into_failures(), vec![ error ]);
+}
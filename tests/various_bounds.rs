@@ -1,5 +1,5 @@
 extern crate range_check;
-use range_check::Check;
+use range_check::{Bounds, Check, ErrorKind, OutOfRangeError, RangeTarget};
 
 
 // These tests work with a non-number type (that is still Copy)
@@ -90,3 +90,27 @@ fn ten() {
     assert!(B.check_range(..= B).is_ok());
     assert!(C.check_range(..= B).is_err());
 }
+
+
+
+// Range types from other crates can be checked against by implementing
+// RangeTarget for them.
+
+struct FromB;
+
+impl RangeTarget<ABC> for FromB {
+    fn contains_value(&self, value: &ABC) -> bool {
+        *value >= B
+    }
+
+    fn into_error(self, value: ABC) -> OutOfRangeError<ABC> {
+        OutOfRangeError::new(Bounds::at_least(B), value, ErrorKind::Outside)
+    }
+}
+
+#[test]
+fn third_party() {
+    assert!(A.check_range(FromB).is_err());
+    assert!(B.check_range(FromB).is_ok());
+    assert!(C.check_range(FromB).is_ok());
+}
@@ -0,0 +1,79 @@
+extern crate range_check;
+use range_check::{Bounds, Check, Position, RangeSet, Violation};
+
+
+#[test]
+fn below() {
+    let err = 0.check_range(1 .. 10).unwrap_err();
+    assert_eq!(err.violation(), Violation::BelowLower);
+    assert_eq!(err.to_string(), "value (0) below range (1..10)");
+}
+
+#[test]
+fn above() {
+    let err = 10.check_range(1 .. 10).unwrap_err();
+    assert_eq!(err.violation(), Violation::AboveUpper);
+    assert_eq!(err.to_string(), "value (10) above range (1..10)");
+}
+
+#[test]
+fn above_inclusive() {
+    let err = 11.check_range(1 ..= 10).unwrap_err();
+    assert_eq!(err.violation(), Violation::AboveUpper);
+    assert_eq!(err.to_string(), "value (11) above range (1..=10)");
+}
+
+#[test]
+fn incomparable() {
+    let err = f64::NAN.check_range(0.0 .. 1.0).unwrap_err();
+    assert_eq!(err.violation(), Violation::Incomparable);
+    assert_eq!(err.to_string(), "value (NaN) cannot be compared with range (0.0..1.0)");
+}
+
+#[test]
+fn incomparable_bound() {
+    let err = 0.5.check_range(0.0 .. f64::NAN).unwrap_err();
+    assert_eq!(err.violation(), Violation::Incomparable);
+}
+
+#[test]
+fn in_gap() {
+    let set: RangeSet<i32> = vec![ Bounds::closed(1, 3), Bounds::closed(7, 9) ].into_iter().collect();
+
+    assert_eq!(5.check_range(&set).unwrap_err().violation(), Violation::InGap);
+    assert_eq!(0.check_range(&set).unwrap_err().violation(), Violation::BelowLower);
+    assert_eq!(10.check_range(&set).unwrap_err().violation(), Violation::AboveUpper);
+
+    assert_eq!(0.check_range(&set).unwrap_err().to_string(),
+               "value (0) below ranges (1..=3, 7..=9)");
+}
+
+
+#[test]
+fn positions() {
+    assert_eq!(0.position_in_range(1 .. 10), Some(Position::Below));
+    assert_eq!(1.position_in_range(1 .. 10), Some(Position::Within));
+    assert_eq!(9.position_in_range(1 .. 10), Some(Position::Within));
+    assert_eq!(10.position_in_range(1 .. 10), Some(Position::Above));
+    assert_eq!(10.position_in_range(1 ..= 10), Some(Position::Within));
+}
+
+#[test]
+fn positions_unbounded() {
+    assert_eq!(i32::MIN.position_in_range(..), Some(Position::Within));
+    assert_eq!(0.position_in_range(1 ..), Some(Position::Below));
+    assert_eq!(10.position_in_range(.. 10), Some(Position::Above));
+}
+
+#[test]
+fn positions_with_bounds() {
+    let bounds = Bounds::open(1, 10);
+    assert_eq!(1.position_in_range(&bounds), Some(Position::Below));
+    assert_eq!(10.position_in_range(bounds), Some(Position::Above));
+}
+
+#[test]
+fn positions_nan() {
+    assert_eq!(f64::NAN.position_in_range(0.0 .. 1.0), None);
+    assert_eq!(f64::NAN.position_in_range(..), None);
+}